@@ -0,0 +1,188 @@
+use alloc::vec::Vec;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Generics, Visibility};
+
+use crate::{generated_variant::GeneratedVariant, snake_case::snake_case_ident};
+
+/// Returns the `MyEnumBuckets` struct, which sorts enum values into one `Vec`
+/// per generated struct.
+///
+/// Variants without a generated struct are kept in `rest`, so no bucket may
+/// be named `rest`.
+pub(crate) fn enum_tokens(
+    enum_name: &Ident,
+    vis: &Visibility,
+    generics: &Generics,
+    generated_variants: &[Option<GeneratedVariant>],
+) -> syn::Result<TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let buckets_name = format_ident!("{}Buckets", enum_name);
+    let vec = quote!(::enum_variant_type::__private::Vec);
+    let bucketed_variants = generated_variants
+        .iter()
+        .flatten()
+        .filter(|generated_variant| generated_variant.impls.r#struct)
+        .collect::<Vec<&GeneratedVariant>>();
+    let has_rest = bucketed_variants.len() < generated_variants.len();
+
+    let bucket_names = bucketed_variants
+        .iter()
+        .map(|generated_variant| snake_case_ident(&generated_variant.variant.ident))
+        .collect::<Vec<Ident>>();
+    for (index, (generated_variant, bucket_name)) in
+        bucketed_variants.iter().zip(&bucket_names).enumerate()
+    {
+        let variant_name = &generated_variant.variant.ident;
+        if bucket_name == "rest" {
+            return Err(syn::Error::new(
+                variant_name.span(),
+                alloc::format!(
+                    "`#[evt(buckets)]` cannot generate the `rest` bucket for `{variant_name}`, \
+                        as `{buckets_name}::rest` holds variants without a generated struct."
+                ),
+            ));
+        }
+        if let Some(existing_index) = bucket_names[..index]
+            .iter()
+            .position(|existing_name| existing_name == bucket_name)
+        {
+            let existing_name = &bucketed_variants[existing_index].variant.ident;
+            return Err(syn::Error::new(
+                variant_name.span(),
+                alloc::format!(
+                    "`#[evt(buckets)]` generates the `{bucket_name}` bucket for both \
+                        `{existing_name}` and `{variant_name}`."
+                ),
+            ));
+        }
+    }
+    let bucket_fields =
+        bucketed_variants
+            .iter()
+            .zip(&bucket_names)
+            .map(|(generated_variant, bucket_name)| {
+                let struct_ty = generated_variant.struct_ty(&ty_generics);
+                let doc = alloc::format!(
+                    "Values of `{enum_name}::{}`.",
+                    generated_variant.variant.ident
+                );
+                quote! {
+                    #[doc = #doc]
+                    pub #bucket_name: #vec<#struct_ty>
+                }
+            });
+    let push_arms =
+        bucketed_variants
+            .iter()
+            .zip(&bucket_names)
+            .map(|(generated_variant, bucket_name)| {
+                let variant_name = &generated_variant.variant.ident;
+                let enum_pattern = generated_variant.fields.enum_pattern();
+                let struct_path = generated_variant.struct_path();
+                let struct_form = generated_variant.fields.struct_form();
+                quote! {
+                    #enum_name::#variant_name #enum_pattern => {
+                        self.#bucket_name.push(#struct_path #struct_form);
+                    }
+                }
+            });
+    let rest_arm = has_rest.then(|| {
+        quote! {
+            enum_variant => self.rest.push(enum_variant),
+        }
+    });
+    let into_enum_iters =
+        bucketed_variants
+            .iter()
+            .zip(&bucket_names)
+            .map(|(generated_variant, bucket_name)| {
+                let variant_name = &generated_variant.variant.ident;
+                let struct_path = generated_variant.struct_path();
+                let struct_form = generated_variant.fields.struct_form();
+                let enum_construction = generated_variant.fields.enum_construction();
+                quote! {
+                    enum_variants.extend(self.#bucket_name.into_iter().map(
+                        |#struct_path #struct_form| #enum_name::#variant_name #enum_construction
+                    ));
+                }
+            });
+    let doc = alloc::format!("Values of [`{enum_name}`], sorted into one `Vec` per variant.");
+    let rest_doc = alloc::format!("Values of `{enum_name}` variants without a generated struct.");
+
+    Ok(quote! {
+        #[doc = #doc]
+        #vis struct #buckets_name #impl_generics #where_clause {
+            #(#bucket_fields,)*
+            #[doc = #rest_doc]
+            pub rest: #vec<#enum_name #ty_generics>,
+        }
+
+        impl #impl_generics core::default::Default for #buckets_name #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#bucket_names: #vec::new(),)*
+                    rest: #vec::new(),
+                }
+            }
+        }
+
+        impl #impl_generics #buckets_name #ty_generics #where_clause {
+            /// Adds the value to the bucket for its variant.
+            #vis fn push(&mut self, enum_variant: #enum_name #ty_generics) {
+                match enum_variant {
+                    #(#push_arms)*
+                    #rest_arm
+                }
+            }
+
+            /// Returns the number of values across all buckets.
+            #vis fn len(&self) -> usize {
+                0 #(+ self.#bucket_names.len())* + self.rest.len()
+            }
+
+            /// Returns whether every bucket is empty.
+            #vis fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+        }
+
+        impl #impl_generics core::iter::FromIterator<#enum_name #ty_generics>
+            for #buckets_name #ty_generics
+        #where_clause {
+            fn from_iter<I>(iter: I) -> Self
+            where
+                I: core::iter::IntoIterator<Item = #enum_name #ty_generics>,
+            {
+                let mut buckets = <Self as core::default::Default>::default();
+                core::iter::Extend::extend(&mut buckets, iter);
+                buckets
+            }
+        }
+
+        impl #impl_generics core::iter::Extend<#enum_name #ty_generics>
+            for #buckets_name #ty_generics
+        #where_clause {
+            fn extend<I>(&mut self, iter: I)
+            where
+                I: core::iter::IntoIterator<Item = #enum_name #ty_generics>,
+            {
+                iter.into_iter().for_each(|enum_variant| self.push(enum_variant));
+            }
+        }
+
+        impl #impl_generics core::iter::IntoIterator for #buckets_name #ty_generics #where_clause {
+            type Item = #enum_name #ty_generics;
+            type IntoIter = ::enum_variant_type::__private::vec::IntoIter<#enum_name #ty_generics>;
+
+            /// Returns the values as the enum, in variant order, followed by
+            /// `rest`.
+            fn into_iter(self) -> Self::IntoIter {
+                let mut enum_variants = #vec::with_capacity(self.len());
+                #(#into_enum_iters)*
+                enum_variants.extend(self.rest);
+                enum_variants.into_iter()
+            }
+        }
+    })
+}
@@ -0,0 +1,140 @@
+use alloc::{string::ToString, vec::Vec};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, Generics, Type, Visibility};
+
+use crate::variant_fields::VariantFields;
+
+/// Returns `StructBuilder` and `Struct::builder()` for a named variant's
+/// struct.
+///
+/// Nothing is generated for unit and tuple structs. A field named `build`, or
+/// `build_enum` when that method is generated, is rejected, as its setter
+/// would clash with the builder's method.
+///
+/// # Parameters
+///
+/// * `build_enum`: Whether to generate `build_enum()`, which uses the `From`
+///   impl for the enum.
+pub(crate) fn struct_tokens(
+    enum_name: &Ident,
+    variant_name: &Ident,
+    vis: &Visibility,
+    generics: &Generics,
+    variant_fields: &VariantFields,
+    build_enum: bool,
+) -> syn::Result<TokenStream> {
+    if !variant_fields.is_named() {
+        return Ok(TokenStream::new());
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let builder_name = format_ident!("{}Builder", variant_name.unraw());
+    let struct_name = variant_name.unraw().to_string();
+    let fields = variant_fields.struct_fields().collect::<Vec<_>>();
+    let field_names = fields
+        .iter()
+        .flat_map(|field| field.ident.as_ref())
+        .collect::<Vec<&Ident>>();
+    if let Some(field_name) = field_names.iter().find(|field_name| {
+        let field_name = field_name.unraw();
+        field_name == "build" || (build_enum && field_name == "build_enum")
+    }) {
+        return Err(syn::Error::new(
+            field_name.span(),
+            alloc::format!(
+                "`#[evt(builder)]` cannot generate a setter for the field `{field_name}` of \
+                    `{struct_name}`, as it clashes with `{builder_name}::{field_name}`."
+            ),
+        ));
+    }
+    let field_tys = fields.iter().map(|field| &field.ty).collect::<Vec<&Type>>();
+    let setter_docs = field_names
+        .iter()
+        .map(|field_name| alloc::format!("Sets `{}`.", field_name.unraw()));
+    let field_values = field_names
+        .iter()
+        .zip(&field_tys)
+        .map(|(field_name, field_ty)| {
+            if is_option(field_ty) {
+                quote!(#field_name: self.#field_name.unwrap_or_default())
+            } else {
+                let field_name_str = field_name.unraw().to_string();
+                quote! {
+                    #field_name: self.#field_name.ok_or(::enum_variant_type::MissingField {
+                        struct_name: #struct_name,
+                        field_name: #field_name_str,
+                    })?
+                }
+            }
+        });
+    let builder_doc = alloc::format!(
+        "Builder for [`{variant_name}`].\n\nFields with an `Option` type default to `None`."
+    );
+    let build_enum_fn = build_enum.then(|| {
+        quote! {
+            /// Returns the enum variant, or an error if a required field is
+            /// not set.
+            #vis fn build_enum(
+                self,
+            ) -> core::result::Result<#enum_name #ty_generics, ::enum_variant_type::MissingField> {
+                self.build().map(<#enum_name #ty_generics as core::convert::From<#variant_name #ty_generics>>::from)
+            }
+        }
+    });
+
+    Ok(quote! {
+        #[doc = #builder_doc]
+        #vis struct #builder_name #impl_generics #where_clause {
+            #(#field_names: core::option::Option<#field_tys>,)*
+        }
+
+        impl #impl_generics core::default::Default for #builder_name #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#field_names: core::option::Option::None,)*
+                }
+            }
+        }
+
+        impl #impl_generics #builder_name #ty_generics #where_clause {
+            #(
+                #[doc = #setter_docs]
+                #vis fn #field_names(mut self, #field_names: #field_tys) -> Self {
+                    self.#field_names = core::option::Option::Some(#field_names);
+                    self
+                }
+            )*
+
+            /// Returns the struct, or an error if a required field is not set.
+            #vis fn build(
+                self,
+            ) -> core::result::Result<#variant_name #ty_generics, ::enum_variant_type::MissingField> {
+                core::result::Result::Ok(#variant_name {
+                    #(#field_values,)*
+                })
+            }
+
+            #build_enum_fn
+        }
+
+        impl #impl_generics #variant_name #ty_generics #where_clause {
+            /// Returns a builder for this struct.
+            #vis fn builder() -> #builder_name #ty_generics {
+                <#builder_name #ty_generics as core::default::Default>::default()
+            }
+        }
+    })
+}
+
+/// Returns whether the type is written as `Option<T>`.
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
@@ -0,0 +1,144 @@
+use alloc::{string::ToString, vec::Vec};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{meta::ParseNestedMeta, token, Generics, Member, Visibility};
+
+use crate::{generated_variant::GeneratedVariant, snake_case, variant_fields::VariantFields};
+
+/// Parameters of `#[evt(constructors)]` and `#[evt(constructors(into_args))]`.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Constructors {
+    /// Whether the constructors take `impl Into<FieldTy>` for each field.
+    ///
+    /// These constructors cannot be `const fn`s.
+    into_args: bool,
+}
+
+impl Constructors {
+    /// Parses the optional arguments of `constructors(..)`.
+    pub(crate) fn parse(nested_meta: &ParseNestedMeta) -> syn::Result<Self> {
+        let mut constructors = Constructors::default();
+        if nested_meta.input.peek(token::Paren) {
+            nested_meta.parse_nested_meta(|constructors_meta| {
+                if constructors_meta.path.is_ident("into_args") {
+                    constructors.into_args = true;
+                    return Ok(());
+                }
+
+                Err(constructors_meta
+                    .error("Expected `#[evt(constructors)]` or `#[evt(constructors(into_args))]`."))
+            })?;
+        }
+
+        Ok(constructors)
+    }
+
+    /// Returns `Struct::new(..)` for a variant's struct.
+    pub(crate) fn struct_tokens(
+        self,
+        variant_name: &Ident,
+        vis: &Visibility,
+        generics: &Generics,
+        variant_fields: &VariantFields,
+    ) -> TokenStream {
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let (impl_generics, ty_generics, where_clause) = if variant_fields.is_unit() {
+            (None, None, None)
+        } else {
+            (Some(impl_generics), Some(ty_generics), where_clause)
+        };
+        let params = self.params(variant_fields);
+        let values = variant_fields
+            .struct_members()
+            .zip(param_names(variant_fields))
+            .map(|(member, param_name)| {
+                if self.into_args {
+                    quote!(#member: #param_name.into())
+                } else {
+                    quote!(#member: #param_name)
+                }
+            });
+        let constness = (!self.into_args).then(|| quote!(const));
+        let doc = alloc::format!("Returns a new `{variant_name}`.");
+
+        quote! {
+            impl #impl_generics #variant_name #ty_generics #where_clause {
+                #[doc = #doc]
+                #[allow(clippy::too_many_arguments)]
+                #vis #constness fn new(#(#params),*) -> Self {
+                    Self { #(#values,)* }
+                }
+            }
+        }
+    }
+
+    /// Returns the `new_<variant>(..)` methods for the enum.
+    ///
+    /// These are built on `Struct::new` and the generated `From` impl, so are
+    /// only generated for variants that have them.
+    pub(crate) fn enum_tokens(
+        self,
+        enum_name: &Ident,
+        vis: &Visibility,
+        generics: &Generics,
+        generated_variants: &[Option<GeneratedVariant>],
+    ) -> TokenStream {
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let methods = generated_variants
+            .iter()
+            .flatten()
+            .filter(|generated_variant| {
+                generated_variant.impls.r#struct && generated_variant.impls.from
+            })
+            .map(|generated_variant| {
+                let variant_name = &generated_variant.variant.ident;
+                let new_fn =
+                    format_ident!("new_{}", snake_case::snake_case(&variant_name.to_string()));
+                let struct_ty = generated_variant.struct_ty(&ty_generics);
+                let params = self.params(&generated_variant.fields);
+                let param_names = param_names(&generated_variant.fields);
+                let doc = alloc::format!("Returns a new `{enum_name}::{variant_name}`.");
+
+                quote! {
+                    #[doc = #doc]
+                    #[allow(clippy::too_many_arguments)]
+                    #vis fn #new_fn(#(#params),*) -> Self {
+                        <Self as core::convert::From<#struct_ty>>::from(
+                            <#struct_ty>::new(#(#param_names),*),
+                        )
+                    }
+                }
+            });
+
+        quote! {
+            impl #impl_generics #enum_name #ty_generics #where_clause {
+                #(#methods)*
+            }
+        }
+    }
+
+    /// Returns the constructor parameters for the struct's fields.
+    fn params(self, variant_fields: &VariantFields) -> Vec<TokenStream> {
+        variant_fields
+            .struct_fields()
+            .zip(param_names(variant_fields))
+            .map(|(field, param_name)| {
+                let ty = &field.ty;
+                if self.into_args {
+                    quote!(#param_name: impl core::convert::Into<#ty>)
+                } else {
+                    quote!(#param_name: #ty)
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns the parameter name for each of the struct's fields, which is the
+/// field name, or `_0`, `_1`, .. for tuple structs.
+fn param_names(variant_fields: &VariantFields) -> impl Iterator<Item = Ident> + '_ {
+    variant_fields.struct_members().map(|member| match member {
+        Member::Named(ident) => ident,
+        Member::Unnamed(index) => format_ident!("_{}", index.index),
+    })
+}
@@ -0,0 +1,128 @@
+use alloc::vec::Vec;
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::quote;
+use syn::{DataEnum, Fields, Generics, Visibility};
+
+use crate::repr::Repr;
+
+/// Returns the discriminant expression of each variant.
+///
+/// Variants without an explicit discriminant are one more than the previous
+/// variant, starting from `0`.
+pub(crate) fn values(data_enum: &DataEnum) -> Vec<TokenStream> {
+    let mut previous = None::<(TokenStream, usize)>;
+    data_enum
+        .variants
+        .iter()
+        .map(|variant| {
+            let (base, offset) = match (&variant.discriminant, &previous) {
+                (Some((_, discriminant)), _) => (quote!(#discriminant), 0),
+                (None, Some((base, offset))) => (base.clone(), offset + 1),
+                (None, None) => (quote!(0), 0),
+            };
+            let value = if offset == 0 {
+                quote!((#base))
+            } else {
+                let offset = Literal::usize_unsuffixed(offset);
+                quote!((#base) + #offset)
+            };
+            previous = Some((base, offset));
+            value
+        })
+        .collect()
+}
+
+/// Returns the type of the enum's discriminant, which is the primitive
+/// representation if there is one, otherwise `isize`.
+pub(crate) fn ty(enum_repr: &Repr) -> TokenStream {
+    match &enum_repr.int {
+        Some(int) => quote!(#int),
+        None => quote!(isize),
+    }
+}
+
+/// Returns the `DISCRIMINANT` constant for a variant's struct.
+pub(crate) fn struct_tokens(
+    variant_name: &Ident,
+    generics: &Generics,
+    discriminant_ty: &TokenStream,
+    discriminant: &TokenStream,
+    is_unit: bool,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let (impl_generics, ty_generics, where_clause) = if is_unit {
+        (None, None, None)
+    } else {
+        (Some(impl_generics), Some(ty_generics), where_clause)
+    };
+
+    quote! {
+        impl #impl_generics #variant_name #ty_generics #where_clause {
+            /// Discriminant of the variant this struct is generated from.
+            pub const DISCRIMINANT: #discriminant_ty = #discriminant;
+        }
+    }
+}
+
+/// Returns the integer conversion for a unit variant's struct.
+///
+/// Only applicable to enums with a primitive representation.
+pub(crate) fn unit_struct_tokens(variant_name: &Ident, int: &Ident) -> TokenStream {
+    quote! {
+        impl core::convert::From<#variant_name> for #int {
+            fn from(_: #variant_name) -> Self {
+                #variant_name::DISCRIMINANT
+            }
+        }
+    }
+}
+
+/// Returns `TryFrom<Int>` and `discriminant(&self)` for the enum.
+///
+/// Only applicable to enums with a primitive representation.
+pub(crate) fn enum_tokens(
+    enum_name: &Ident,
+    vis: &Visibility,
+    generics: &Generics,
+    int: &Ident,
+    data_enum: &DataEnum,
+    discriminants: &[TokenStream],
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let unit_variant_checks = data_enum
+        .variants
+        .iter()
+        .zip(discriminants)
+        .filter(|(variant, _)| matches!(variant.fields, Fields::Unit))
+        .map(|(variant, discriminant)| {
+            let variant_name = &variant.ident;
+            quote! {
+                if value == #discriminant {
+                    return core::result::Result::Ok(#enum_name::#variant_name);
+                }
+            }
+        });
+
+    quote! {
+        impl #impl_generics core::convert::TryFrom<#int> for #enum_name #ty_generics
+        #where_clause {
+            type Error = #int;
+
+            fn try_from(value: #int) -> Result<Self, Self::Error> {
+                #(#unit_variant_checks)*
+
+                core::result::Result::Err(value)
+            }
+        }
+
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            /// Returns the discriminant of this variant.
+            #vis fn discriminant(&self) -> #int {
+                // SAFETY: An enum with a primitive representation is laid out
+                // with its discriminant first, so the pointer can be read as
+                // the discriminant.
+                unsafe { *(self as *const Self).cast::<#int>() }
+            }
+        }
+    }
+}
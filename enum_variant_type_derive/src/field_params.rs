@@ -0,0 +1,137 @@
+use alloc::vec::Vec;
+use proc_macro2::Ident;
+use proc_macro_roids::namespace_parameters;
+use syn::{punctuated::Punctuated, Expr, ExprLit, Field, Lit, Meta, Path, Token};
+
+use crate::suggest;
+
+/// Parameters accepted by an `evt` attribute on a field.
+const FIELD_PARAMS: &[&str] = &["rename", "attr", "strip_attrs", "skip", "default"];
+
+/// Attributes that are kept on a field with `#[evt(strip_attrs)]`.
+const ATTRIBUTES_TO_KEEP: &[&str] = &["doc", "cfg"];
+
+/// Parameters specified by `#[evt(..)]` on a field.
+#[derive(Debug, Default)]
+pub(crate) struct FieldParams {
+    /// Name of the field in the generated struct.
+    pub(crate) rename: Option<Ident>,
+    /// Attributes to attach to the field in the generated struct.
+    pub(crate) attrs: Vec<Meta>,
+    /// Attributes on the variant field to not copy to the generated struct.
+    ///
+    /// `Some` with no paths means only `doc` and `cfg` attributes are copied.
+    pub(crate) strip_attrs: Option<Vec<Path>>,
+    /// Whether to leave the field out of the generated struct.
+    pub(crate) skip: bool,
+    /// Value of a skipped field when converting the struct into the enum.
+    ///
+    /// `None` means `Default::default()` is used.
+    pub(crate) default: Option<Expr>,
+}
+
+impl FieldParams {
+    /// Parses the `evt` attributes on a field.
+    ///
+    /// # Parameters
+    ///
+    /// * `field`: The variant field.
+    /// * `ns`: The `evt` namespace.
+    pub(crate) fn parse(field: &Field, ns: &Path) -> syn::Result<Self> {
+        let mut field_params = FieldParams::default();
+
+        for meta in namespace_parameters(&field.attrs, ns) {
+            if meta.path().is_ident("rename") {
+                // `#[evt(rename = "field_name")]`
+                let field_name = match &meta {
+                    Meta::NameValue(name_value) => match &name_value.value {
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(field_name),
+                            ..
+                        }) => Some(field_name),
+                        _ => None,
+                    },
+                    _ => None,
+                }
+                .ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        &meta,
+                        "Expected field `evt` attribute argument in the form: \
+                            `#[evt(rename = \"field_name\")]`.",
+                    )
+                })?;
+
+                if field.ident.is_none() {
+                    return Err(syn::Error::new_spanned(
+                        &meta,
+                        "`#[evt(rename = \"..\")]` can only be used on named fields.",
+                    ));
+                }
+
+                field_params.rename = Some(field_name.parse()?);
+            } else if meta.path().is_ident("attr") {
+                // `#[evt(attr(serde(default)))]`
+                let list = meta.require_list()?;
+                field_params
+                    .attrs
+                    .extend(list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?);
+            } else if meta.path().is_ident("strip_attrs") {
+                // `#[evt(strip_attrs)]`, `#[evt(strip_attrs(serde))]`
+                let strip_attrs = field_params.strip_attrs.get_or_insert_with(Vec::new);
+                match &meta {
+                    Meta::Path(_) => {}
+                    Meta::List(list) => list.parse_nested_meta(|nested_meta| {
+                        strip_attrs.push(nested_meta.path);
+                        Ok(())
+                    })?,
+                    Meta::NameValue(_) => {
+                        return Err(syn::Error::new_spanned(
+                            &meta,
+                            "Expected field `evt` attribute argument in the form: \
+                                `#[evt(strip_attrs)]` or `#[evt(strip_attrs(attr_name))]`.",
+                        ));
+                    }
+                }
+            } else if meta.path().is_ident("skip") {
+                // `#[evt(skip)]`
+                meta.require_path_only()?;
+                field_params.skip = true;
+            } else if meta.path().is_ident("default") {
+                // `#[evt(skip, default = expr)]`
+                let name_value = meta.require_name_value()?;
+                field_params.default = Some(name_value.value.clone());
+            } else {
+                return Err(suggest::unknown_param_error(
+                    meta.path(),
+                    FIELD_PARAMS,
+                    "Expected one of `rename`, `attr`, `strip_attrs`, `skip`, or `default`.",
+                ));
+            }
+        }
+
+        if let (false, Some(default)) = (field_params.skip, &field_params.default) {
+            return Err(syn::Error::new_spanned(
+                default,
+                "`#[evt(default = ..)]` can only be used together with `#[evt(skip)]`.",
+            ));
+        }
+
+        Ok(field_params)
+    }
+
+    /// Returns whether an attribute on the variant field should be copied to
+    /// the generated struct's field.
+    pub(crate) fn copies_attr(&self, path: &Path, ns: &Path) -> bool {
+        if path == ns {
+            return false;
+        }
+
+        match &self.strip_attrs {
+            None => true,
+            Some(strip_attrs) if strip_attrs.is_empty() => ATTRIBUTES_TO_KEEP
+                .iter()
+                .any(|attr_to_keep| path.is_ident(attr_to_keep)),
+            Some(strip_attrs) => !strip_attrs.iter().any(|strip_attr| strip_attr == path),
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use alloc::{string::ToString, vec::Vec};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{punctuated::Punctuated, Generics, Token, Variant, Visibility};
+
+use crate::{
+    generated_variant::GeneratedVariant,
+    snake_case,
+    views::{self, Borrow},
+};
+
+/// Returns the `fold`, `fold_ref`, and `fold_mut` methods for the enum.
+///
+/// Each method takes one closure per variant, in declaration order.
+pub(crate) fn enum_tokens(
+    enum_name: &Ident,
+    vis: &Visibility,
+    generics: &Generics,
+    variants: &Punctuated<Variant, Token![,]>,
+    generated_variants: &[Option<GeneratedVariant>],
+) -> syn::Result<TokenStream> {
+    let generated_variants = generated_variants
+        .iter()
+        .zip(variants)
+        .map(|(generated_variant, variant)| {
+            let variant_name = &variant.ident;
+            generated_variant
+                .as_ref()
+                .filter(|generated_variant| generated_variant.impls.r#struct)
+                .ok_or_else(|| {
+                    syn::Error::new(
+                        variant_name.span(),
+                        alloc::format!(
+                            "`#[evt(fold)]` requires a struct for every variant, but none is \
+                                generated for `{variant_name}`."
+                        ),
+                    )
+                })
+        })
+        .collect::<syn::Result<Vec<&GeneratedVariant>>>()?;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let view_generics = views::view_generics(generics);
+    let (_, view_ty_generics, _) = view_generics.split_for_impl();
+
+    let closure_names = generated_variants
+        .iter()
+        .map(|generated_variant| {
+            let variant_name = snake_case::snake_case(&generated_variant.variant.ident.to_string());
+            format_ident!("on_{}", variant_name)
+        })
+        .collect::<Vec<Ident>>();
+
+    let closure_param = |generated_variant: &GeneratedVariant, borrow: Option<Borrow>| match borrow
+    {
+        _ if generated_variant.fields.is_unit() => generated_variant.struct_path(),
+        None => generated_variant.struct_ty(&ty_generics),
+        Some(borrow) => {
+            let view_path =
+                generated_variant.item_path(&borrow.view_name(&generated_variant.variant.ident));
+            quote!(#view_path #view_ty_generics)
+        }
+    };
+    let arm =
+        |generated_variant: &GeneratedVariant, closure_name: &Ident, borrow: Option<Borrow>| {
+            let variant_name = &generated_variant.variant.ident;
+            let enum_pattern = generated_variant.fields.enum_pattern();
+            let struct_form = generated_variant.fields.struct_form();
+            let constructor = match borrow {
+                _ if generated_variant.fields.is_unit() => generated_variant.struct_path(),
+                None => generated_variant.struct_path(),
+                Some(borrow) => generated_variant.item_path(&borrow.view_name(variant_name)),
+            };
+
+            quote! {
+                #enum_name::#variant_name #enum_pattern => #closure_name(#constructor #struct_form),
+            }
+        };
+    let method = |borrow: Option<Borrow>| {
+        let closure_params = generated_variants.iter().zip(&closure_names).map(
+            |(generated_variant, closure_name)| {
+                let param = closure_param(generated_variant, borrow);
+                quote!(#closure_name: impl core::ops::FnOnce(#param) -> R)
+            },
+        );
+        let arms = generated_variants
+            .iter()
+            .zip(&closure_names)
+            .map(|(generated_variant, closure_name)| arm(generated_variant, closure_name, borrow));
+
+        match borrow {
+            None => quote! {
+                /// Converts this into the variant's struct, and passes it to the
+                /// closure for that variant.
+                #[allow(clippy::too_many_arguments)]
+                #vis fn fold<R>(self, #(#closure_params),*) -> R {
+                    match self {
+                        #(#arms)*
+                    }
+                }
+            },
+            Some(Borrow::Ref) => quote! {
+                /// Passes the borrowed fields of this variant to the closure for
+                /// that variant.
+                #[allow(clippy::too_many_arguments)]
+                #vis fn fold_ref<'evt, R>(&'evt self, #(#closure_params),*) -> R {
+                    match self {
+                        #(#arms)*
+                    }
+                }
+            },
+            Some(Borrow::Mut) => quote! {
+                /// Passes the mutably borrowed fields of this variant to the
+                /// closure for that variant.
+                #[allow(clippy::too_many_arguments)]
+                #vis fn fold_mut<'evt, R>(&'evt mut self, #(#closure_params),*) -> R {
+                    match self {
+                        #(#arms)*
+                    }
+                }
+            },
+        }
+    };
+
+    let fold = method(None);
+    let fold_ref = method(Some(Borrow::Ref));
+    let fold_mut = method(Some(Borrow::Mut));
+
+    Ok(quote! {
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            #fold
+            #fold_ref
+            #fold_mut
+        }
+    })
+}
@@ -0,0 +1,52 @@
+use alloc::vec::Vec;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Type, TypeGenerics, Variant};
+
+use crate::{impls::Impls, transition::Transition, variant_fields::VariantFields};
+
+/// A variant, and the items generated for it.
+///
+/// Used to generate items on the enum that refer to each variant's struct.
+#[derive(Debug)]
+pub(crate) struct GeneratedVariant<'a> {
+    /// The enum variant.
+    pub(crate) variant: &'a Variant,
+    /// Fields of the variant, and how they map to the struct's fields.
+    pub(crate) fields: VariantFields,
+    /// Path of the module the struct is placed in, relative to the enum.
+    pub(crate) module_path: Vec<Ident>,
+    /// Which items are generated for the variant.
+    pub(crate) impls: Impls,
+    /// Transitions from the variant's struct to other variants' structs.
+    pub(crate) transitions: Vec<Transition>,
+    /// Type of the response to the variant, when used as a request.
+    pub(crate) response: Option<Type>,
+}
+
+impl GeneratedVariant<'_> {
+    /// Returns the path to the generated struct, relative to the enum.
+    pub(crate) fn struct_path(&self) -> TokenStream {
+        self.item_path(&self.variant.ident)
+    }
+
+    /// Returns the struct's type, relative to the enum.
+    ///
+    /// Unit structs are not generic, so the generics are only applied to
+    /// structs with fields.
+    pub(crate) fn struct_ty(&self, ty_generics: &TypeGenerics) -> TokenStream {
+        let struct_path = self.struct_path();
+        if self.fields.is_unit() {
+            struct_path
+        } else {
+            quote!(#struct_path #ty_generics)
+        }
+    }
+
+    /// Returns the path to an item in the struct's module, relative to the
+    /// enum.
+    pub(crate) fn item_path(&self, item_name: &Ident) -> TokenStream {
+        let module_path = &self.module_path;
+        quote!(#(#module_path::)* #item_name)
+    }
+}
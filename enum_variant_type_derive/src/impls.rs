@@ -0,0 +1,91 @@
+use syn::{meta::ParseNestedMeta, MetaList, Path};
+
+use crate::suggest;
+
+/// Names of the items that may be selected in `impls(..)` and `skip(..)`.
+const IMPL_NAMES: &[&str] = &["struct", "from", "try_from", "markers"];
+
+/// Which items to generate for a variant.
+///
+/// Selected using `#[evt(impls(struct, from, try_from, markers))]`, and
+/// deselected using `#[evt(skip(try_from))]`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Impls {
+    /// Whether to generate the struct declaration.
+    pub(crate) r#struct: bool,
+    /// Whether to generate `impl From<Struct> for Enum`.
+    pub(crate) from: bool,
+    /// Whether to generate `impl TryFrom<Enum> for Struct`.
+    pub(crate) try_from: bool,
+    /// Whether to generate the `implement_marker_traits(..)` impls.
+    pub(crate) markers: bool,
+}
+
+impl Impls {
+    /// Every item is generated.
+    pub(crate) const ALL: Impls = Impls {
+        r#struct: true,
+        from: true,
+        try_from: true,
+        markers: true,
+    };
+    /// No items are generated.
+    pub(crate) const NONE: Impls = Impls {
+        r#struct: false,
+        from: false,
+        try_from: false,
+        markers: false,
+    };
+
+    /// Parses the items listed in an enum level `impls(..)` or `skip(..)`.
+    pub(crate) fn parse_nested_meta(nested_meta: &ParseNestedMeta) -> syn::Result<Self> {
+        let mut impls = Impls::NONE;
+        nested_meta.parse_nested_meta(|impl_meta| impls.select(&impl_meta.path))?;
+
+        Ok(impls)
+    }
+
+    /// Parses the items listed in a variant level `impls(..)` or `skip(..)`.
+    pub(crate) fn parse_meta_list(meta_list: &MetaList) -> syn::Result<Self> {
+        let mut impls = Impls::NONE;
+        meta_list.parse_nested_meta(|impl_meta| impls.select(&impl_meta.path))?;
+
+        Ok(impls)
+    }
+
+    /// Selects the item with the given name.
+    fn select(&mut self, path: &Path) -> syn::Result<()> {
+        if path.is_ident("struct") {
+            self.r#struct = true;
+        } else if path.is_ident("from") {
+            self.from = true;
+        } else if path.is_ident("try_from") {
+            self.try_from = true;
+        } else if path.is_ident("markers") {
+            self.markers = true;
+        } else {
+            return Err(suggest::unknown_param_error(
+                path,
+                IMPL_NAMES,
+                "Expected one of `struct`, `from`, `try_from`, or `markers`.",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether no items are generated.
+    pub(crate) fn is_none(self) -> bool {
+        self == Impls::NONE
+    }
+
+    /// Returns these impls without the ones selected in `other`.
+    pub(crate) fn without(self, other: Impls) -> Impls {
+        Impls {
+            r#struct: self.r#struct && !other.r#struct,
+            from: self.from && !other.from,
+            try_from: self.try_from && !other.try_from,
+            markers: self.markers && !other.markers,
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use alloc::string::ToString;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, punctuated::Punctuated, Generics, Token, Variant, Visibility};
+
+use crate::generated_variant::GeneratedVariant;
+
+/// Returns the name of the kind enum, e.g. `MyEnumKind`.
+pub(crate) fn kind_name(enum_name: &Ident) -> Ident {
+    format_ident!("{}Kind", enum_name.unraw())
+}
+
+/// Returns the `MyEnumKind` enum, `MyEnum::kind(&self)`, and the
+/// `VariantKind` impl for each generated struct.
+pub(crate) fn enum_tokens(
+    enum_name: &Ident,
+    vis: &Visibility,
+    generics: &Generics,
+    variants: &Punctuated<Variant, Token![,]>,
+    generated_variants: &[Option<GeneratedVariant>],
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let kind_name = kind_name(enum_name);
+    let variant_names = variants
+        .iter()
+        .map(|variant| &variant.ident)
+        .collect::<alloc::vec::Vec<&Ident>>();
+    let variant_name_strs = variant_names
+        .iter()
+        .map(|variant_name| variant_name.unraw().to_string());
+    let variant_count = variants.len();
+    let variant_kind_impls = generated_variants
+        .iter()
+        .flatten()
+        .filter(|generated_variant| generated_variant.impls.r#struct)
+        .map(|generated_variant| {
+            let variant_name = &generated_variant.variant.ident;
+            let struct_ty = generated_variant.struct_ty(&ty_generics);
+            let impl_generics = (!generated_variant.fields.is_unit()).then_some(&impl_generics);
+            let where_clause = (!generated_variant.fields.is_unit()).then_some(where_clause);
+
+            quote! {
+                impl #impl_generics ::enum_variant_type::VariantKind for #struct_ty #where_clause {
+                    type Kind = #kind_name;
+
+                    const KIND: #kind_name = #kind_name::#variant_name;
+                }
+            }
+        });
+    let doc = alloc::format!("Kinds of [`{enum_name}`] variants, without their fields.");
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #vis enum #kind_name {
+            #(
+                #[allow(missing_docs)]
+                #variant_names,
+            )*
+        }
+
+        impl #kind_name {
+            /// Number of kinds.
+            pub const COUNT: usize = #variant_count;
+            /// Every kind, in declaration order.
+            pub const ALL: [#kind_name; #variant_count] = [#(#kind_name::#variant_names),*];
+
+            /// Returns the name of the variant.
+            #vis const fn name(self) -> &'static str {
+                match self {
+                    #(#kind_name::#variant_names => #variant_name_strs,)*
+                }
+            }
+
+            /// Returns the position of the variant in the enum's declaration.
+            #vis const fn index(self) -> usize {
+                self as usize
+            }
+        }
+
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            /// Returns the kind of this variant.
+            #vis fn kind(&self) -> #kind_name {
+                match self {
+                    #(#enum_name::#variant_names { .. } => #kind_name::#variant_names,)*
+                }
+            }
+        }
+
+        #(#variant_kind_impls)*
+    }
+}
@@ -0,0 +1,227 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, Generics, Visibility};
+
+use crate::kind;
+
+/// Returns the smallest unsigned integer type with at least one bit per
+/// variant.
+fn bits_ty(enum_name: &Ident, variant_count: usize) -> syn::Result<Ident> {
+    let bits_ty = match variant_count {
+        0..=8 => "u8",
+        9..=16 => "u16",
+        17..=32 => "u32",
+        33..=64 => "u64",
+        65..=128 => "u128",
+        _ => {
+            return Err(syn::Error::new(
+                enum_name.span(),
+                "`#[evt(kind_set)]` supports enums with at most 128 variants.",
+            ));
+        }
+    };
+
+    Ok(format_ident!("{}", bits_ty))
+}
+
+/// Returns the `MyEnumSet` bitset over `MyEnumKind`.
+pub(crate) fn enum_tokens(
+    enum_name: &Ident,
+    vis: &Visibility,
+    generics: &Generics,
+    variant_count: usize,
+) -> syn::Result<TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let bits_ty = bits_ty(enum_name, variant_count)?;
+    let kind_name = kind::kind_name(enum_name);
+    let set_name = format_ident!("{}Set", enum_name.unraw());
+    let all_bits = if variant_count == 0 {
+        quote!(0)
+    } else {
+        quote!(#bits_ty::MAX >> (#bits_ty::BITS as usize - #variant_count))
+    };
+    let doc = alloc::format!("Set of [`{enum_name}`] variant kinds, stored as bits.");
+
+    let ops = [
+        ("BitOr", "bitor", "union"),
+        ("BitAnd", "bitand", "intersection"),
+        ("BitXor", "bitxor", "symmetric_difference"),
+        ("Sub", "sub", "difference"),
+    ]
+    .into_iter()
+    .map(|(op_trait, op_fn, set_fn)| {
+        let op_assign_trait = format_ident!("{}Assign", op_trait);
+        let op_assign_fn = format_ident!("{}_assign", op_fn);
+        let op_trait = format_ident!("{}", op_trait);
+        let op_fn = format_ident!("{}", op_fn);
+        let set_fn = format_ident!("{}", set_fn);
+        quote! {
+            impl core::ops::#op_trait for #set_name {
+                type Output = Self;
+
+                fn #op_fn(self, other: Self) -> Self {
+                    self.#set_fn(other)
+                }
+            }
+
+            impl core::ops::#op_assign_trait for #set_name {
+                fn #op_assign_fn(&mut self, other: Self) {
+                    *self = self.#set_fn(other);
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+        #vis struct #set_name {
+            bits: #bits_ty,
+        }
+
+        impl #set_name {
+            /// Returns an empty set.
+            #vis const fn new() -> Self {
+                Self { bits: 0 }
+            }
+
+            /// Returns a set of every kind.
+            #vis const fn all() -> Self {
+                Self { bits: #all_bits }
+            }
+
+            /// Returns a set of the kind of the variant struct `T`.
+            #vis const fn of<T>() -> Self
+            where
+                T: ::enum_variant_type::VariantKind<Kind = #kind_name>,
+            {
+                Self::from_kind(T::KIND)
+            }
+
+            /// Returns a set of the given kind.
+            #vis const fn from_kind(kind: #kind_name) -> Self {
+                Self { bits: 1 << kind as usize }
+            }
+
+            /// Returns the bits of this set, where bit `n` is the variant at
+            /// index `n`.
+            #vis const fn bits(self) -> #bits_ty {
+                self.bits
+            }
+
+            /// Adds the kind to this set, returning whether it was newly added.
+            #vis fn insert(&mut self, kind: #kind_name) -> bool {
+                let inserted = !self.contains_kind(kind);
+                self.bits |= Self::from_kind(kind).bits;
+                inserted
+            }
+
+            /// Removes the kind from this set, returning whether it was present.
+            #vis fn remove(&mut self, kind: #kind_name) -> bool {
+                let removed = self.contains_kind(kind);
+                self.bits &= !Self::from_kind(kind).bits;
+                removed
+            }
+
+            /// Returns whether this set contains the kind of the given variant.
+            #vis fn contains #impl_generics (&self, enum_variant: &#enum_name #ty_generics) -> bool
+            #where_clause
+            {
+                self.contains_kind(enum_variant.kind())
+            }
+
+            /// Returns whether this set contains the kind.
+            #vis const fn contains_kind(&self, kind: #kind_name) -> bool {
+                self.bits & Self::from_kind(kind).bits != 0
+            }
+
+            /// Returns the number of kinds in this set.
+            #vis const fn len(&self) -> usize {
+                self.bits.count_ones() as usize
+            }
+
+            /// Returns whether this set is empty.
+            #vis const fn is_empty(&self) -> bool {
+                self.bits == 0
+            }
+
+            /// Returns the kinds in either set.
+            #vis const fn union(self, other: Self) -> Self {
+                Self { bits: self.bits | other.bits }
+            }
+
+            /// Returns the kinds in both sets.
+            #vis const fn intersection(self, other: Self) -> Self {
+                Self { bits: self.bits & other.bits }
+            }
+
+            /// Returns the kinds in this set but not the other.
+            #vis const fn difference(self, other: Self) -> Self {
+                Self { bits: self.bits & !other.bits }
+            }
+
+            /// Returns the kinds in exactly one of the sets.
+            #vis const fn symmetric_difference(self, other: Self) -> Self {
+                Self { bits: self.bits ^ other.bits }
+            }
+
+            /// Returns the kinds not in this set.
+            #vis const fn complement(self) -> Self {
+                Self { bits: !self.bits & Self::all().bits }
+            }
+
+            /// Returns an iterator over the kinds in this set, in declaration
+            /// order.
+            #vis fn iter(&self) -> impl core::iter::Iterator<Item = #kind_name> + use<> {
+                let set = *self;
+                #kind_name::ALL
+                    .into_iter()
+                    .filter(move |kind| set.contains_kind(*kind))
+            }
+        }
+
+        #(#ops)*
+
+        impl core::ops::Not for #set_name {
+            type Output = Self;
+
+            fn not(self) -> Self {
+                self.complement()
+            }
+        }
+
+        impl core::convert::From<#kind_name> for #set_name {
+            fn from(kind: #kind_name) -> Self {
+                Self::from_kind(kind)
+            }
+        }
+
+        impl core::iter::FromIterator<#kind_name> for #set_name {
+            fn from_iter<I>(iter: I) -> Self
+            where
+                I: core::iter::IntoIterator<Item = #kind_name>,
+            {
+                let mut set = Self::new();
+                core::iter::Extend::extend(&mut set, iter);
+                set
+            }
+        }
+
+        impl core::iter::Extend<#kind_name> for #set_name {
+            fn extend<I>(&mut self, iter: I)
+            where
+                I: core::iter::IntoIterator<Item = #kind_name>,
+            {
+                iter.into_iter().for_each(|kind| {
+                    self.insert(kind);
+                });
+            }
+        }
+
+        impl core::fmt::Debug for #set_name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_set().entries(self.iter()).finish()
+            }
+        }
+    })
+}
@@ -0,0 +1,2266 @@
+#![deny(missing_debug_implementations, missing_docs)]
+#![no_std]
+#![recursion_limit = "128"]
+
+//! Proc macro derive for [`enum_variant_type`].
+//!
+//! See the [`enum_variant_type`] crate for documentation.
+//!
+//! [`enum_variant_type`]: https://docs.rs/enum_variant_type/
+
+extern crate alloc;
+extern crate proc_macro;
+
+mod buckets;
+mod builder;
+mod constructors;
+mod discriminant;
+mod field_params;
+mod fold;
+mod generated_variant;
+mod impls;
+mod kind;
+mod kind_set;
+mod map;
+mod metadata;
+mod module_params;
+mod repr;
+mod response;
+mod serde_repr;
+mod snake_case;
+mod suggest;
+mod tag;
+mod transition;
+mod tuple_conv;
+mod variant_fields;
+mod variant_layout;
+mod variant_map;
+mod variant_params;
+mod views;
+
+use alloc::vec::Vec;
+use proc_macro::TokenStream;
+use proc_macro2::Ident;
+use quote::quote;
+use syn::{
+    parenthesized, parse_macro_input, parse_quote, punctuated::Punctuated, Attribute, Data,
+    DataEnum, DeriveInput, Fields, LitStr, Meta, Path, Token,
+};
+
+use crate::{
+    constructors::Constructors,
+    generated_variant::GeneratedVariant,
+    impls::Impls,
+    module_params::{ModuleParams, ModuleTree},
+    repr::Repr,
+    serde_repr::SerdeRepr,
+    variant_fields::VariantFields,
+    variant_params::VariantParams,
+};
+
+/// Attributes that should be copied across.
+const ATTRIBUTES_TO_COPY: &[&str] = &["doc", "cfg", "allow", "deny"];
+
+/// Parameters accepted by an `evt` attribute on the enum.
+const ENUM_PARAMS: &[&str] = &[
+    "strict",
+    "opt_in",
+    "impls",
+    "skip",
+    "module",
+    "derive",
+    "attr",
+    "implement_marker_traits",
+    "as_variant_ref",
+    "discriminant",
+    "metadata",
+    "fold",
+    "map",
+    "views",
+    "buckets",
+    "kind_set",
+    "variant_map",
+    "serde_repr",
+    "constructors",
+    "builder",
+    "tuple_conv",
+    "response_derive",
+];
+
+/// Derives a struct for each enum variant.
+///
+/// Struct fields including their attributes are copied over.
+#[cfg(not(tarpaulin_include))]
+#[proc_macro_derive(EnumVariantType, attributes(evt))]
+pub fn enum_variant_type(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    // Need to do this, otherwise we can't unit test the input.
+    enum_variant_type_impl(ast).into()
+}
+
+#[inline]
+fn enum_variant_type_impl(ast: DeriveInput) -> proc_macro2::TokenStream {
+    try_enum_variant_type_impl(ast).unwrap_or_else(syn::Error::into_compile_error)
+}
+
+fn try_enum_variant_type_impl(ast: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let enum_name = &ast.ident;
+    let vis = &ast.vis;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let data_enum = data_enum(&ast)?;
+    let variants = &data_enum.variants;
+
+    let mut module_params = None::<ModuleParams>;
+    let mut derive_for_all_variants = None::<Attribute>;
+    let mut attrs_for_all_variants = Vec::<Meta>::new();
+    let mut marker_trait_paths = Vec::<Path>::new();
+    let mut enum_repr = Repr::default();
+    let mut strict = false;
+    let mut enum_impls = Impls::ALL;
+    let mut opt_in = false;
+    let mut enum_skip_impls = Impls::NONE;
+    let mut as_variant_ref = false;
+    let mut discriminant = false;
+    let mut metadata = false;
+    let mut fold = false;
+    let mut map = false;
+    let mut views = false;
+    let mut buckets = false;
+    let mut kind_set = false;
+    let mut variant_map = false;
+    let mut serde_repr = None::<LitStr>;
+    let mut constructors = None::<Constructors>;
+    let mut builder = false;
+    let mut tuple_conv = false;
+    let mut response_derive = None::<Attribute>;
+
+    for attr in ast.attrs.iter() {
+        if attr.path().is_ident("repr") {
+            // Layout relevant hints are propagated to each struct, e.g. "repr(C)".
+            if let Meta::List(list) = &attr.meta {
+                enum_repr.parse_meta_list(list)?;
+            }
+        } else if attr.path().is_ident("evt") {
+            attr.parse_nested_meta(|nested_meta| {
+                // `#[evt(strict)]`
+                if nested_meta.path.is_ident("strict") {
+                    strict = true;
+                    return Ok(());
+                }
+
+                // `#[evt(opt_in)]`
+                if nested_meta.path.is_ident("opt_in") {
+                    opt_in = true;
+                    return Ok(());
+                }
+
+                // `#[evt(impls(struct, from))]`
+                if nested_meta.path.is_ident("impls") {
+                    enum_impls = Impls::parse_nested_meta(&nested_meta)?;
+                    return Ok(());
+                }
+
+                // `#[evt(skip(try_from))]`
+                if nested_meta.path.is_ident("skip") {
+                    enum_skip_impls = Impls::parse_nested_meta(&nested_meta)?;
+                    return Ok(());
+                }
+
+                // `#[evt(module = "some_module_name")]`, `#[evt(module(name = "a::b", ..))]`
+                if nested_meta.path.is_ident("module") {
+                    module_params = Some(ModuleParams::parse(&nested_meta)?);
+                    return Ok(());
+                }
+                // `#[evt(derive(Clone, Debug))]`
+                if nested_meta.path.is_ident("derive") {
+                    let mut items = Vec::new();
+                    nested_meta.parse_nested_meta(|parse_nested_meta| {
+                        items.push(parse_nested_meta.path);
+                        Ok(())
+                    })?;
+
+                    derive_for_all_variants = Some(parse_quote! {
+                        #[derive( #(#items),* )]
+                    });
+                    return Ok(());
+                }
+
+                // `#[evt(attr(serde(deny_unknown_fields), must_use))]`
+                if nested_meta.path.is_ident("attr") {
+                    let content;
+                    parenthesized!(content in nested_meta.input);
+                    attrs_for_all_variants
+                        .extend(Punctuated::<Meta, Token![,]>::parse_terminated(&content)?);
+
+                    return Ok(());
+                }
+
+                // `#[evt(implement_marker_traits(MarkerTrait1, MarkerTrait2))]`
+                if nested_meta.path.is_ident("implement_marker_traits") {
+                    nested_meta.parse_nested_meta(|parse_nested_meta| {
+                        marker_trait_paths.push(parse_nested_meta.path);
+                        Ok(())
+                    })?;
+
+                    return Ok(());
+                }
+
+                // `#[evt(as_variant_ref)]`
+                if nested_meta.path.is_ident("as_variant_ref") {
+                    as_variant_ref = true;
+                    return Ok(());
+                }
+
+                // `#[evt(discriminant)]`
+                if nested_meta.path.is_ident("discriminant") {
+                    discriminant = true;
+                    return Ok(());
+                }
+
+                // `#[evt(metadata)]`
+                if nested_meta.path.is_ident("metadata") {
+                    metadata = true;
+                    return Ok(());
+                }
+
+                // `#[evt(fold)]`
+                if nested_meta.path.is_ident("fold") {
+                    fold = true;
+                    return Ok(());
+                }
+
+                // `#[evt(map)]`
+                if nested_meta.path.is_ident("map") {
+                    map = true;
+                    return Ok(());
+                }
+
+                // `#[evt(views)]`
+                if nested_meta.path.is_ident("views") {
+                    views = true;
+                    return Ok(());
+                }
+
+                // `#[evt(buckets)]`
+                if nested_meta.path.is_ident("buckets") {
+                    buckets = true;
+                    return Ok(());
+                }
+
+                // `#[evt(kind_set)]`
+                if nested_meta.path.is_ident("kind_set") {
+                    kind_set = true;
+                    return Ok(());
+                }
+
+                // `#[evt(variant_map)]`
+                if nested_meta.path.is_ident("variant_map") {
+                    variant_map = true;
+                    return Ok(());
+                }
+
+                // `#[evt(serde_repr = "internal(type)")]`
+                if nested_meta.path.is_ident("serde_repr") {
+                    serde_repr = Some(nested_meta.value()?.parse()?);
+                    return Ok(());
+                }
+
+                // `#[evt(constructors)]`, `#[evt(constructors(into_args))]`
+                if nested_meta.path.is_ident("constructors") {
+                    constructors = Some(Constructors::parse(&nested_meta)?);
+                    return Ok(());
+                }
+
+                // `#[evt(builder)]`
+                if nested_meta.path.is_ident("builder") {
+                    builder = true;
+                    return Ok(());
+                }
+
+                // `#[evt(tuple_conv)]`
+                if nested_meta.path.is_ident("tuple_conv") {
+                    tuple_conv = true;
+                    return Ok(());
+                }
+
+                // `#[evt(response_derive(Debug, PartialEq))]`
+                if nested_meta.path.is_ident("response_derive") {
+                    let mut items = Vec::new();
+                    nested_meta.parse_nested_meta(|parse_nested_meta| {
+                        items.push(parse_nested_meta.path);
+                        Ok(())
+                    })?;
+
+                    response_derive = Some(parse_quote! {
+                        #[derive( #(#items),* )]
+                    });
+                    return Ok(());
+                }
+
+                Err(suggest::unknown_param_error(
+                    &nested_meta.path,
+                    ENUM_PARAMS,
+                    "Unexpected usage of `evt` attribute, please see examples at: \
+                        <https://docs.rs/enum_variant_type/>",
+                ))
+            })?;
+        }
+    }
+
+    if as_variant_ref {
+        variant_layout::validate(enum_name, &enum_repr, &ast.generics)?;
+    }
+
+    let serde_repr = serde_repr
+        .map(|serde_repr| SerdeRepr::new(&serde_repr, enum_name, &ast.attrs))
+        .transpose()?;
+
+    let enum_module_path = module_params
+        .as_ref()
+        .map(|module_params| module_params.segments(enum_name))
+        .unwrap_or_default();
+    let mut module_tree = ModuleTree::default();
+    module_tree.node_mut(&enum_module_path);
+
+    let enum_impls = enum_impls.without(enum_skip_impls);
+    let ns: Path = parse_quote!(evt);
+    let discriminants = discriminant::values(data_enum);
+    let discriminant_ty = discriminant::ty(&enum_repr);
+    let variants_params = variants
+        .iter()
+        .map(|variant| VariantParams::parse(&variant.attrs, &ns, strict))
+        .collect::<syn::Result<Vec<VariantParams>>>()?;
+    let tags = tag::values(variants, &variants_params)?;
+    let struct_declarations_iter = variants.iter()
+        .zip(discriminants.iter())
+        .zip(variants_params)
+        .enumerate()
+        .filter(|(_, (_, variant_params))| !variant_params.impls(enum_impls, opt_in).is_none())
+        .map(|(variant_index, ((variant, variant_discriminant), variant_params))| {
+
+        let impls = variant_params.impls(enum_impls, opt_in);
+        let variant_name = &variant.ident;
+        let attrs_to_copy = variant
+            .attrs
+            .iter()
+            .filter(|attribute| {
+                ATTRIBUTES_TO_COPY
+                    .iter()
+                    .any(|attr_to_copy| attribute.path().is_ident(attr_to_copy))
+            })
+            .collect::<Vec<&Attribute>>();
+
+        let mut variant_struct_attrs = attrs_for_all_variants
+            .iter()
+            .filter(|attr| variant_params.includes_enum_attr(attr))
+            .cloned()
+            .chain(variant_params.struct_attrs.iter().cloned())
+            .fold(
+                proc_macro2::TokenStream::new(),
+                |mut attrs_tokens, variant_struct_attr| {
+                    attrs_tokens.extend(quote!(#[#variant_struct_attr]));
+                    attrs_tokens
+                },
+            );
+
+        let mut variant_fields = VariantFields::new(&variant.fields, vis, &ns)?;
+        if serde_repr.is_some() {
+            // The generated serde impls replace any derived ones.
+            variant_fields.strip_struct_field_attrs("serde");
+        }
+
+        let struct_repr = match &variant_params.repr {
+            Some(variant_repr) => {
+                variant_repr.validate_for_struct(variant_fields.struct_fields().count())?;
+                variant_repr.clone()
+            }
+            None => enum_repr.struct_repr(),
+        };
+        variant_struct_attrs.extend(struct_repr.to_attr());
+        let fields_with_vis = variant_fields.struct_fields().collect::<Vec<_>>();
+
+        let data_struct = match &variant.fields {
+            Fields::Unit => quote! {
+                struct #variant_name;
+            },
+            Fields::Unnamed(..) => {
+                quote! {
+                    struct #variant_name #ty_generics (#(#fields_with_vis,)*) #where_clause;
+                }
+            }
+            Fields::Named(..) => quote! {
+                struct #variant_name #ty_generics #where_clause {
+                    #(#fields_with_vis,)*
+                }
+            },
+        };
+
+        // TODO: This generates invalid code if the type parameter is not used by this variant.
+        let struct_form = variant_fields.struct_form();
+        let enum_pattern = variant_fields.enum_pattern();
+        let enum_construction = variant_fields.enum_construction();
+        let skipped_fields_doc = variant_fields.skipped_fields_doc(enum_name, variant_name);
+        let deconstruct_variant_struct = if variant_fields.is_unit() {
+            proc_macro2::TokenStream::new()
+        } else {
+            quote! {
+                let #variant_name #struct_form = variant_struct;
+            }
+        };
+        let impl_from_variant_for_enum = quote! {
+            impl #impl_generics core::convert::From<#variant_name #ty_generics>
+                for #enum_name #ty_generics
+            #where_clause {
+                fn from(variant_struct: #variant_name #ty_generics) -> Self {
+                    // Deconstruct the parameter.
+                    #deconstruct_variant_struct
+
+                    #enum_name::#variant_name #enum_construction
+                }
+            }
+        };
+
+        let impl_try_from_enum_for_variant = quote! {
+            impl #impl_generics core::convert::TryFrom<#enum_name #ty_generics>
+                for #variant_name #ty_generics
+            #where_clause {
+                type Error = #enum_name #ty_generics;
+
+                fn try_from(enum_variant: #enum_name #ty_generics) -> Result<Self, Self::Error> {
+                    // Deconstruct the variant.
+                    if let #enum_name::#variant_name #enum_pattern = enum_variant {
+                        core::result::Result::Ok(#variant_name #struct_form)
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+        };
+
+        let mut struct_declarations = proc_macro2::TokenStream::new();
+        if impls.r#struct {
+            struct_declarations.extend(quote! {
+                #(#attrs_to_copy)*
+                #(#[doc = #skipped_fields_doc])*
+                #derive_for_all_variants
+                #variant_struct_attrs
+                #vis #data_struct
+            });
+        }
+        if impls.from {
+            struct_declarations.extend(impl_from_variant_for_enum);
+        }
+        if impls.try_from {
+            struct_declarations.extend(impl_try_from_enum_for_variant);
+        }
+        if impls.markers {
+            struct_declarations.extend(quote! {
+                #(impl #ty_generics #marker_trait_paths for #variant_name #ty_generics {})*
+            });
+        }
+        if discriminant && impls.r#struct {
+            struct_declarations.extend(discriminant::struct_tokens(
+                variant_name,
+                &ast.generics,
+                &discriminant_ty,
+                variant_discriminant,
+                variant_fields.is_unit(),
+            ));
+            if let (Some(int), true) = (&enum_repr.int, variant_fields.is_unit()) {
+                struct_declarations.extend(discriminant::unit_struct_tokens(variant_name, int));
+            }
+        }
+        if let (Some(tags), true) = (&tags, impls.r#struct) {
+            struct_declarations.extend(tag::struct_tokens(
+                variant_name,
+                &ast.generics,
+                tags[variant_index],
+                variant_fields.is_unit(),
+            ));
+        }
+        if metadata && impls.r#struct {
+            struct_declarations.extend(metadata::struct_tokens(
+                variant_name,
+                variant_index,
+                &ast.generics,
+                &variant_fields,
+            ));
+        }
+        if (views || fold) && impls.r#struct {
+            struct_declarations.extend(views::struct_tokens(
+                enum_name,
+                variant_name,
+                vis,
+                &ast.generics,
+                &variant_fields,
+            ));
+        }
+        if let (Some(serde_repr), true) = (&serde_repr, impls.r#struct) {
+            struct_declarations.extend(serde_repr.struct_tokens(
+                enum_name,
+                variants,
+                variant_index,
+                &ast.generics,
+                &variant_fields,
+            ));
+        }
+        if let (Some(constructors), true) = (constructors, impls.r#struct) {
+            struct_declarations.extend(constructors.struct_tokens(
+                variant_name,
+                vis,
+                &ast.generics,
+                &variant_fields,
+            ));
+        }
+        if builder && impls.r#struct {
+            struct_declarations.extend(builder::struct_tokens(
+                enum_name,
+                variant_name,
+                vis,
+                &ast.generics,
+                &variant_fields,
+                impls.from,
+            )?);
+        }
+        if tuple_conv && impls.r#struct {
+            struct_declarations.extend(tuple_conv::struct_tokens(
+                enum_name,
+                variant_name,
+                &ast.generics,
+                &variant_fields,
+                impls.from,
+            ));
+        }
+        if as_variant_ref && impls.r#struct {
+            struct_declarations.extend(variant_layout::variant_tokens(
+                enum_name,
+                variant_name,
+                &variant_fields,
+                &struct_repr,
+            ));
+        }
+
+        let module_path = enum_module_path
+            .iter()
+            .chain(variant_params.module.iter())
+            .cloned()
+            .collect::<Vec<Ident>>();
+
+        let generated_variant = GeneratedVariant {
+            variant,
+            fields: variant_fields,
+            module_path,
+            impls,
+            transitions: variant_params.transitions,
+            response: variant_params.response,
+        };
+
+        Ok::<_, syn::Error>((variant_index, generated_variant, struct_declarations))
+    });
+    let mut generated_variants = variants
+        .iter()
+        .map(|_| None)
+        .collect::<Vec<Option<GeneratedVariant>>>();
+    for struct_declarations in struct_declarations_iter {
+        let (variant_index, generated_variant, struct_declarations) = struct_declarations?;
+        let struct_name = generated_variant
+            .impls
+            .r#struct
+            .then_some(&generated_variant.variant.ident);
+        module_tree.insert(
+            &generated_variant.module_path,
+            struct_name,
+            struct_declarations,
+        );
+        generated_variants[variant_index] = Some(generated_variant);
+    }
+
+    if tuple_conv {
+        tuple_conv::validate(enum_name, &ast.generics, &generated_variants)?;
+    }
+
+    let mut tokens = module_tree.into_tokens(module_params.as_ref(), enum_name, vis);
+    if as_variant_ref {
+        tokens.extend(variant_layout::enum_tokens(
+            enum_name, vis, &enum_repr, data_enum,
+        ));
+    }
+    if metadata {
+        tokens.extend(metadata::enum_tokens(
+            enum_name,
+            &ast.generics,
+            variants,
+            &generated_variants,
+        ));
+    }
+    if fold {
+        tokens.extend(fold::enum_tokens(
+            enum_name,
+            vis,
+            &ast.generics,
+            variants,
+            &generated_variants,
+        )?);
+    }
+    if map {
+        tokens.extend(map::enum_tokens(
+            enum_name,
+            vis,
+            &ast.generics,
+            &generated_variants,
+        ));
+    }
+    if let Some(constructors) = constructors {
+        tokens.extend(constructors.enum_tokens(enum_name, vis, &ast.generics, &generated_variants));
+    }
+    if buckets {
+        tokens.extend(buckets::enum_tokens(
+            enum_name,
+            vis,
+            &ast.generics,
+            &generated_variants,
+        )?);
+    }
+    if kind_set || variant_map || tags.is_some() {
+        tokens.extend(kind::enum_tokens(
+            enum_name,
+            vis,
+            &ast.generics,
+            variants,
+            &generated_variants,
+        ));
+    }
+    if kind_set {
+        tokens.extend(kind_set::enum_tokens(
+            enum_name,
+            vis,
+            &ast.generics,
+            variants.len(),
+        )?);
+    }
+    if variant_map {
+        tokens.extend(variant_map::enum_tokens(enum_name, vis, &ast.generics));
+    }
+    tokens.extend(transition::enum_tokens(
+        enum_name,
+        vis,
+        &ast.generics,
+        variants,
+        &generated_variants,
+    )?);
+    tokens.extend(response::enum_tokens(
+        enum_name,
+        vis,
+        &ast.generics,
+        response_derive.as_ref(),
+        &generated_variants,
+    )?);
+    if let Some(tags) = &tags {
+        tokens.extend(tag::enum_tokens(
+            enum_name,
+            vis,
+            &ast.generics,
+            variants,
+            tags,
+        ));
+    }
+    if let (true, Some(int)) = (discriminant, &enum_repr.int) {
+        tokens.extend(discriminant::enum_tokens(
+            enum_name,
+            vis,
+            &ast.generics,
+            int,
+            data_enum,
+            &discriminants,
+        ));
+    }
+
+    Ok(tokens)
+}
+
+fn data_enum(ast: &DeriveInput) -> syn::Result<&DataEnum> {
+    if let Data::Enum(data_enum) = &ast.data {
+        Ok(data_enum)
+    } else {
+        Err(syn::Error::new_spanned(
+            &ast.ident,
+            "`EnumVariantType` derive can only be used on an enum.",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::string::ToString;
+    use pretty_assertions::assert_eq;
+    use quote::quote;
+    use syn::{parse_quote, DeriveInput};
+
+    use super::enum_variant_type_impl;
+
+    #[test]
+    fn generates_correct_tokens_for_basic_enum() {
+        let ast: DeriveInput = parse_quote! {
+            pub enum MyEnum {
+                /// Unit variant.
+                #[evt(derive(Clone, Copy, Debug, PartialEq))]
+                Unit,
+                /// Tuple variant.
+                #[evt(derive(Debug))]
+                Tuple(u32, u64),
+                /// Struct variant.
+                Struct {
+                    field_0: u32,
+                    field_1: u64,
+                },
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            /// Unit variant.
+            #[derive(Clone, Copy, Debug, PartialEq)]
+            pub struct Unit;
+
+            impl core::convert::From<Unit> for MyEnum {
+                fn from(variant_struct: Unit) -> Self {
+                    MyEnum::Unit
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for Unit {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::Unit = enum_variant {
+                        core::result::Result::Ok(Unit)
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+
+            /// Tuple variant.
+            #[derive(Debug)]
+            pub struct Tuple(pub u32, pub u64,);
+
+            impl core::convert::From<Tuple> for MyEnum {
+                fn from(variant_struct: Tuple) -> Self {
+                    let Tuple(_0, _1,) = variant_struct;
+                    MyEnum::Tuple(_0, _1,)
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for Tuple {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::Tuple(_0, _1,) = enum_variant {
+                        core::result::Result::Ok(Tuple(_0, _1,))
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+
+            /// Struct variant.
+            pub struct Struct {
+                pub field_0: u32,
+                pub field_1: u64,
+            }
+
+            impl core::convert::From<Struct> for MyEnum {
+                fn from(variant_struct: Struct) -> Self {
+                    let Struct { field_0, field_1, } = variant_struct;
+                    MyEnum::Struct { field_0, field_1, }
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for Struct {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::Struct { field_0, field_1, } = enum_variant {
+                        core::result::Result::Ok(Struct { field_0, field_1, })
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn skips_variants_marked_with_evt_skip() {
+        let ast: DeriveInput = parse_quote! {
+            pub enum MyEnum {
+                /// Unit variant.
+                #[evt(derive(Clone, Copy, Debug, PartialEq))]
+                Unit,
+                /// Skipped variant.
+                #[evt(skip)]
+                UnitSkipped,
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            /// Unit variant.
+            #[derive(Clone, Copy, Debug, PartialEq)]
+            pub struct Unit;
+
+            impl core::convert::From<Unit> for MyEnum {
+                fn from(variant_struct: Unit) -> Self {
+                    MyEnum::Unit
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for Unit {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::Unit = enum_variant {
+                        core::result::Result::Ok(Unit)
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn generates_selected_impls() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(impls(struct, from, markers), implement_marker_traits(MarkerTrait1))]
+            pub enum MyEnum {
+                A,
+                #[evt(skip(markers))]
+                B,
+                #[evt(impls(try_from))]
+                C,
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            pub struct A;
+
+            impl core::convert::From<A> for MyEnum {
+                fn from(variant_struct: A) -> Self {
+                    MyEnum::A
+                }
+            }
+
+            impl MarkerTrait1 for A {}
+
+            pub struct B;
+
+            impl core::convert::From<B> for MyEnum {
+                fn from(variant_struct: B) -> Self {
+                    MyEnum::B
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for C {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::C = enum_variant {
+                        core::result::Result::Ok(C)
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn only_generates_tagged_variants_when_opt_in() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(opt_in)]
+            pub enum MyEnum {
+                A,
+                #[evt(include)]
+                B,
+                #[evt(derive(Debug))]
+                C,
+                #[evt(skip)]
+                D,
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            pub struct B;
+
+            impl core::convert::From<B> for MyEnum {
+                fn from(variant_struct: B) -> Self {
+                    MyEnum::B
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for B {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::B = enum_variant {
+                        core::result::Result::Ok(B)
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+
+            #[derive(Debug)]
+            pub struct C;
+
+            impl core::convert::From<C> for MyEnum {
+                fn from(variant_struct: C) -> Self {
+                    MyEnum::C
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for C {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::C = enum_variant {
+                        core::result::Result::Ok(C)
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn put_variants_in_module() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(module = "example")]
+            pub enum MyEnum {
+                A,
+                B
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            pub mod example {
+                use super::*;
+
+                pub struct A;
+
+                impl core::convert::From<A> for MyEnum {
+                    fn from(variant_struct: A) -> Self {
+                        MyEnum::A
+                    }
+                }
+
+                impl core::convert::TryFrom<MyEnum> for A {
+                    type Error = MyEnum;
+                    fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                        if let MyEnum::A = enum_variant {
+                            core::result::Result::Ok(A)
+                        } else {
+                            core::result::Result::Err(enum_variant)
+                        }
+                    }
+                }
+
+                pub struct B;
+
+                impl core::convert::From<B> for MyEnum {
+                    fn from(variant_struct: B) -> Self {
+                        MyEnum::B
+                    }
+                }
+
+                impl core::convert::TryFrom<MyEnum> for B {
+                    type Error = MyEnum;
+                    fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                        if let MyEnum::B = enum_variant {
+                            core::result::Result::Ok(B)
+                        } else {
+                            core::result::Result::Err(enum_variant)
+                        }
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn put_variants_in_nested_module_with_options() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(module(
+                name = "a::r#type",
+                vis = "pub(crate)",
+                attrs(doc = "Variant types.", allow(dead_code)),
+                imports(crate::Thing),
+                reexport,
+            ))]
+            pub enum MyEnum {
+                A,
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            pub(crate) mod a {
+                #[doc = "Variant types."]
+                #[allow(dead_code)]
+                pub(crate) mod r#type {
+                    use super::super::MyEnum;
+                    use crate::Thing;
+
+                    pub struct A;
+
+                    impl core::convert::From<A> for MyEnum {
+                        fn from(variant_struct: A) -> Self {
+                            MyEnum::A
+                        }
+                    }
+
+                    impl core::convert::TryFrom<MyEnum> for A {
+                        type Error = MyEnum;
+                        fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                            if let MyEnum::A = enum_variant {
+                                core::result::Result::Ok(A)
+                            } else {
+                                core::result::Result::Err(enum_variant)
+                            }
+                        }
+                    }
+                }
+            }
+
+            pub use a::r#type::{A};
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn put_variants_in_snake_case_module_when_name_not_specified() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(module)]
+            enum HTTPRequest {
+                Get,
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            mod http_request {
+                use super::*;
+
+                struct Get;
+
+                impl core::convert::From<Get> for HTTPRequest {
+                    fn from(variant_struct: Get) -> Self {
+                        HTTPRequest::Get
+                    }
+                }
+
+                impl core::convert::TryFrom<HTTPRequest> for Get {
+                    type Error = HTTPRequest;
+                    fn try_from(enum_variant: HTTPRequest) -> Result<Self, Self::Error> {
+                        if let HTTPRequest::Get = enum_variant {
+                            core::result::Result::Ok(Get)
+                        } else {
+                            core::result::Result::Err(enum_variant)
+                        }
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn put_variants_in_variant_level_modules() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(module = "cmd")]
+            pub enum Command {
+                #[evt(module = "fs")]
+                Read,
+                Help,
+                #[evt(module = "fs")]
+                Write,
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            pub mod cmd {
+                use super::*;
+
+                pub struct Help;
+
+                impl core::convert::From<Help> for Command {
+                    fn from(variant_struct: Help) -> Self {
+                        Command::Help
+                    }
+                }
+
+                impl core::convert::TryFrom<Command> for Help {
+                    type Error = Command;
+                    fn try_from(enum_variant: Command) -> Result<Self, Self::Error> {
+                        if let Command::Help = enum_variant {
+                            core::result::Result::Ok(Help)
+                        } else {
+                            core::result::Result::Err(enum_variant)
+                        }
+                    }
+                }
+
+                pub mod fs {
+                    use super::super::*;
+
+                    pub struct Read;
+
+                    impl core::convert::From<Read> for Command {
+                        fn from(variant_struct: Read) -> Self {
+                            Command::Read
+                        }
+                    }
+
+                    impl core::convert::TryFrom<Command> for Read {
+                        type Error = Command;
+                        fn try_from(enum_variant: Command) -> Result<Self, Self::Error> {
+                            if let Command::Read = enum_variant {
+                                core::result::Result::Ok(Read)
+                            } else {
+                                core::result::Result::Err(enum_variant)
+                            }
+                        }
+                    }
+
+                    pub struct Write;
+
+                    impl core::convert::From<Write> for Command {
+                        fn from(variant_struct: Write) -> Self {
+                            Command::Write
+                        }
+                    }
+
+                    impl core::convert::TryFrom<Command> for Write {
+                        type Error = Command;
+                        fn try_from(enum_variant: Command) -> Result<Self, Self::Error> {
+                            if let Command::Write = enum_variant {
+                                core::result::Result::Ok(Write)
+                            } else {
+                                core::result::Result::Err(enum_variant)
+                            }
+                        }
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn applies_field_level_params() {
+        let ast: DeriveInput = parse_quote! {
+            pub enum MyEnum {
+                Struct {
+                    /// Renamed field.
+                    #[evt(rename = "renamed", attr(serde(default)))]
+                    field_0: u32,
+                    #[serde(skip)]
+                    #[evt(strip_attrs)]
+                    field_1: u64,
+                },
+                Tuple(#[serde(skip)] #[allow(dead_code)] #[evt(strip_attrs(serde))] u32),
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            pub struct Struct {
+                /// Renamed field.
+                #[serde(default)]
+                pub renamed: u32,
+                pub field_1: u64,
+            }
+
+            impl core::convert::From<Struct> for MyEnum {
+                fn from(variant_struct: Struct) -> Self {
+                    let Struct { renamed: field_0, field_1, } = variant_struct;
+                    MyEnum::Struct { field_0, field_1, }
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for Struct {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::Struct { field_0, field_1, } = enum_variant {
+                        core::result::Result::Ok(Struct { renamed: field_0, field_1, })
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+
+            pub struct Tuple(#[allow(dead_code)] pub u32,);
+
+            impl core::convert::From<Tuple> for MyEnum {
+                fn from(variant_struct: Tuple) -> Self {
+                    let Tuple(_0,) = variant_struct;
+                    MyEnum::Tuple(_0,)
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for Tuple {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::Tuple(_0,) = enum_variant {
+                        core::result::Result::Ok(Tuple(_0,))
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn skips_fields_marked_with_evt_skip() {
+        let ast: DeriveInput = parse_quote! {
+            pub enum MyEnum {
+                /// Struct variant.
+                Struct {
+                    field_0: u32,
+                    #[evt(skip, default = 5)]
+                    field_1: u64,
+                },
+                Tuple(#[evt(skip)] u32, u64),
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            /// Struct variant.
+            #[doc = ""]
+            #[doc = "Conversion from `MyEnum::Struct` is lossy, the following fields are not stored:"]
+            #[doc = ""]
+            #[doc = "* `field_1`: Set to `5` when converted back."]
+            pub struct Struct {
+                pub field_0: u32,
+            }
+
+            impl core::convert::From<Struct> for MyEnum {
+                fn from(variant_struct: Struct) -> Self {
+                    let Struct { field_0, } = variant_struct;
+                    MyEnum::Struct { field_0, field_1: 5, }
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for Struct {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::Struct { field_0, field_1: _, } = enum_variant {
+                        core::result::Result::Ok(Struct { field_0, })
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+
+            #[doc = ""]
+            #[doc = "Conversion from `MyEnum::Tuple` is lossy, the following fields are not stored:"]
+            #[doc = ""]
+            #[doc = "* `0`: Set to `Default::default()` when converted back."]
+            pub struct Tuple(pub u64,);
+
+            impl core::convert::From<Tuple> for MyEnum {
+                fn from(variant_struct: Tuple) -> Self {
+                    let Tuple(_1,) = variant_struct;
+                    MyEnum::Tuple(core::default::Default::default(), _1,)
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for Tuple {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::Tuple(_, _1,) = enum_variant {
+                        core::result::Result::Ok(Tuple(_1,))
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn rejects_rename_on_tuple_fields() {
+        let ast: DeriveInput = parse_quote! {
+            pub enum MyEnum {
+                Tuple(#[evt(rename = "value")] u32),
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "`#[evt(rename = \"..\")]` can only be used on named fields."
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn derive_traits_for_all_variants() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(derive(Debug))]
+            pub enum MyEnum {
+                A,
+                #[evt(derive(Clone))]
+                B
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            #[derive(Debug)]
+            pub struct A;
+
+            impl core::convert::From<A> for MyEnum {
+                fn from(variant_struct: A) -> Self {
+                    MyEnum::A
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for A {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::A = enum_variant {
+                        core::result::Result::Ok(A)
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+
+            #[derive(Debug)]
+            #[derive(Clone)]
+            pub struct B;
+
+            impl core::convert::From<B> for MyEnum {
+                fn from(variant_struct: B) -> Self {
+                    MyEnum::B
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for B {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::B = enum_variant {
+                        core::result::Result::Ok(B)
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn attach_attributes_to_all_variants() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(attr(serde(deny_unknown_fields), must_use))]
+            pub enum MyEnum {
+                A,
+                #[evt(no_attr(serde))]
+                B,
+                #[evt(no_attr)]
+                C,
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            #[serde(deny_unknown_fields)]
+            #[must_use]
+            pub struct A;
+
+            impl core::convert::From<A> for MyEnum {
+                fn from(variant_struct: A) -> Self {
+                    MyEnum::A
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for A {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::A = enum_variant {
+                        core::result::Result::Ok(A)
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+
+            #[must_use]
+            pub struct B;
+
+            impl core::convert::From<B> for MyEnum {
+                fn from(variant_struct: B) -> Self {
+                    MyEnum::B
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for B {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::B = enum_variant {
+                        core::result::Result::Ok(B)
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+
+            pub struct C;
+
+            impl core::convert::From<C> for MyEnum {
+                fn from(variant_struct: C) -> Self {
+                    MyEnum::C
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for C {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::C = enum_variant {
+                        core::result::Result::Ok(C)
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn strict_mode_attaches_attributes_wrapped_in_attr() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(strict)]
+            pub enum MyEnum {
+                #[evt(derive(Debug), attr(must_use))]
+                A,
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            #[derive(Debug)]
+            #[must_use]
+            pub struct A;
+
+            impl core::convert::From<A> for MyEnum {
+                fn from(variant_struct: A) -> Self {
+                    MyEnum::A
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for A {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::A = enum_variant {
+                        core::result::Result::Ok(A)
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn strict_mode_rejects_unknown_variant_params_with_suggestion() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(strict)]
+            pub enum MyEnum {
+                #[evt(drive(Debug))]
+                A,
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "Unknown `evt` parameter `drive`, did you mean `derive`?"
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn strict_mode_rejects_unknown_variant_params_without_suggestion() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(strict)]
+            pub enum MyEnum {
+                #[evt(serde(rename_all = "camelCase"))]
+                A,
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "Unknown `evt` parameter `serde`. Attributes for the generated struct must be wrapped in `attr(..)` when `#[evt(strict)]` is used."
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn rejects_unknown_enum_params_with_suggestion() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(modul = "example")]
+            pub enum MyEnum {
+                A,
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "Unknown `evt` parameter `modul`, did you mean `module`?"
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn derive_marker_trait() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(implement_marker_traits(MarkerTrait1))]
+            pub enum MyEnum {
+                A,
+                B
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            pub struct A;
+
+            impl core::convert::From<A> for MyEnum {
+                fn from(variant_struct: A) -> Self {
+                    MyEnum::A
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for A {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::A = enum_variant {
+                        core::result::Result::Ok(A)
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+
+            impl MarkerTrait1 for A {}
+
+            pub struct B;
+
+            impl core::convert::From<B> for MyEnum {
+                fn from(variant_struct: B) -> Self {
+                    MyEnum::B
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for B {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::B = enum_variant {
+                        core::result::Result::Ok(B)
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+
+            impl MarkerTrait1 for B {}
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn derive_marker_repr() {
+        let ast: DeriveInput = parse_quote! {
+            #[derive(Debug)]
+            #[repr(C)]
+            pub enum MyEnum {
+                A { i: i64 },
+                B { i: i64 },
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+
+            #[repr(C)]
+            pub struct A { pub i: i64, }
+
+            impl core::convert::From<A> for MyEnum {
+                fn from(variant_struct: A) -> Self {
+                    let A { i, } = variant_struct;
+                    MyEnum::A { i, }
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for A {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::A { i, } = enum_variant {
+                        core::result::Result::Ok(A { i, })
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+
+            #[repr(C)]
+            pub struct B { pub i: i64, }
+
+            impl core::convert::From<B> for MyEnum {
+                fn from(variant_struct: B) -> Self {
+                    let B { i, } = variant_struct;
+                    MyEnum::B { i, }
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for B {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::B { i, } = enum_variant {
+                        core::result::Result::Ok(B { i, })
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn propagates_layout_repr_hints_to_structs() {
+        let ast: DeriveInput = parse_quote! {
+            #[repr(C, u8, align(16))]
+            pub enum MyEnum {
+                A,
+                #[evt(repr(transparent))]
+                B(u32),
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            #[repr(C, align(16))]
+            pub struct A;
+
+            impl core::convert::From<A> for MyEnum {
+                fn from(variant_struct: A) -> Self {
+                    MyEnum::A
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for A {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::A = enum_variant {
+                        core::result::Result::Ok(A)
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+
+            #[repr(transparent)]
+            pub struct B(pub u32,);
+
+            impl core::convert::From<B> for MyEnum {
+                fn from(variant_struct: B) -> Self {
+                    let B(_0,) = variant_struct;
+                    MyEnum::B(_0,)
+                }
+            }
+
+            impl core::convert::TryFrom<MyEnum> for B {
+                type Error = MyEnum;
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    if let MyEnum::B(_0,) = enum_variant {
+                        core::result::Result::Ok(B(_0,))
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn rejects_primitive_repr_on_variant() {
+        let ast: DeriveInput = parse_quote! {
+            pub enum MyEnum {
+                #[evt(repr(u8))]
+                A,
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "`repr(u8)` cannot be used on the generated struct, primitive representations are only valid on enums."
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn rejects_transparent_repr_on_multi_field_variant() {
+        let ast: DeriveInput = parse_quote! {
+            pub enum MyEnum {
+                #[evt(repr(transparent))]
+                A(u32, u64),
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "`repr(transparent)` can only be used on variants with a single field."
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn rejects_as_variant_ref_without_repr_c() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(as_variant_ref)]
+            #[repr(u8)]
+            pub enum MyEnum {
+                A(u32),
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "`#[evt(as_variant_ref)]` requires the enum to be `#[repr(C)]` or `#[repr(C, Int)]`."
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn rejects_as_variant_ref_on_generic_enum() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(as_variant_ref)]
+            #[repr(C)]
+            pub enum MyEnum<T> {
+                A(T),
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "`#[evt(as_variant_ref)]` cannot be used on generic enums."
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn generates_discriminant_constants_and_conversions() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(discriminant, impls(struct))]
+            #[repr(u8)]
+            pub enum MyEnum {
+                A = 2,
+                B(u32),
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            pub struct A;
+            impl A {
+                /// Discriminant of the variant this struct is generated from.
+                pub const DISCRIMINANT: u8 = (2);
+            }
+            impl core::convert::From<A> for u8 {
+                fn from(_: A) -> Self {
+                    A::DISCRIMINANT
+                }
+            }
+
+            pub struct B(pub u32,);
+            impl B {
+                /// Discriminant of the variant this struct is generated from.
+                pub const DISCRIMINANT: u8 = (2) + 1;
+            }
+
+            impl core::convert::TryFrom<u8> for MyEnum {
+                type Error = u8;
+
+                fn try_from(value: u8) -> Result<Self, Self::Error> {
+                    if value == (2) {
+                        return core::result::Result::Ok(MyEnum::A);
+                    }
+
+                    core::result::Result::Err(value)
+                }
+            }
+
+            impl MyEnum {
+                /// Returns the discriminant of this variant.
+                pub fn discriminant(&self) -> u8 {
+                    // SAFETY: An enum with a primitive representation is laid out
+                    // with its discriminant first, so the pointer can be read as
+                    // the discriminant.
+                    unsafe { *(self as *const Self).cast::<u8>() }
+                }
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn generates_metadata_constants() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(metadata, impls(struct))]
+            pub enum MyEnum {
+                A { value: Vec<u8> },
+                #[evt(skip)]
+                B(u32),
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            pub struct A {
+                pub value: Vec<u8>,
+            }
+            impl A {
+                /// Name of the variant this struct is generated from.
+                pub const VARIANT_NAME: &'static str = "A";
+                /// Position of the variant in the enum's declaration.
+                pub const VARIANT_INDEX: usize = 0usize;
+                /// Names of this struct's fields.
+                pub const FIELD_NAMES: &'static [&'static str] = &["value"];
+                /// Types of this struct's fields, formatted by `stringify!`.
+                pub const FIELD_TYPES: &'static [&'static str] = &[core::stringify!(Vec<u8>)];
+            }
+
+            impl MyEnum {
+                /// Number of variants in this enum.
+                pub const VARIANT_COUNT: usize = 2usize;
+                /// Metadata about each variant in this enum.
+                pub const VARIANTS: &'static [::enum_variant_type::VariantInfo] = &[
+                    ::enum_variant_type::VariantInfo {
+                        name: "A",
+                        index: 0usize,
+                        field_names: &["value"],
+                        field_types: &[core::stringify!(Vec<u8>)],
+                        has_struct: true,
+                    },
+                    ::enum_variant_type::VariantInfo {
+                        name: "B",
+                        index: 1usize,
+                        field_names: &["0"],
+                        field_types: &[core::stringify!(u32)],
+                        has_struct: false,
+                    }
+                ];
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn rejects_fold_when_variant_struct_is_skipped() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(fold)]
+            pub enum MyEnum {
+                A,
+                #[evt(skip)]
+                B,
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "`#[evt(fold)]` requires a struct for every variant, but none is generated for `B`."
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn rejects_transition_to_unknown_variant() {
+        let ast: DeriveInput = parse_quote! {
+            pub enum MyEnum {
+                #[evt(transition(to = C, via = "c"))]
+                A,
+                B,
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "Unknown transition target `C` on `MyEnum`."
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn rejects_response_on_variant_without_from() {
+        let ast: DeriveInput = parse_quote! {
+            pub enum MyEnum {
+                #[evt(skip(from), response = u32)]
+                A,
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "`#[evt(response = ..)]` requires a struct and `From` impl for `A`, but they are not both generated."
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn rejects_duplicate_response_types() {
+        let ast: DeriveInput = parse_quote! {
+            pub enum MyEnum {
+                #[evt(response = User)]
+                A,
+                #[evt(response = User)]
+                B(u32),
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "The response type of `B` is also the response type of `A`. Each variant needs a distinct response type."
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn rejects_duplicate_tags() {
+        let ast: DeriveInput = parse_quote! {
+            pub enum MyEnum {
+                #[evt(tag = 0x12)]
+                A,
+                #[evt(tag = 18)]
+                B,
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "Tag `18` of `B` is already used by `A`."
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn rejects_missing_tags() {
+        let ast: DeriveInput = parse_quote! {
+            pub enum MyEnum {
+                #[evt(tag = 1)]
+                A,
+                B,
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "`#[evt(tag = ..)]` is missing on `B`. Every variant needs a tag when any variant has one."
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn rejects_serde_repr_that_does_not_match_enum_tagging() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(serde_repr = "external")]
+            #[serde(tag = "type")]
+            pub enum MyEnum {
+                A,
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "`#[evt(serde_repr = \"external\")]` does not match the `#[serde(..)]` tagging of `MyEnum`."
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn generates_constructors() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(skip(try_from), constructors)]
+            pub enum MyEnum {
+                Tuple(u32, u64),
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            pub struct Tuple(pub u32, pub u64,);
+
+            impl core::convert::From<Tuple> for MyEnum {
+                fn from(variant_struct: Tuple) -> Self {
+                    // Deconstruct the parameter.
+                    let Tuple(_0, _1,) = variant_struct;
+
+                    MyEnum::Tuple(_0, _1,)
+                }
+            }
+
+            impl Tuple {
+                #[doc = "Returns a new `Tuple`."]
+                #[allow(clippy::too_many_arguments)]
+                pub const fn new(_0: u32, _1: u64) -> Self {
+                    Self { 0: _0, 1: _1, }
+                }
+            }
+
+            impl MyEnum {
+                #[doc = "Returns a new `MyEnum::Tuple`."]
+                #[allow(clippy::too_many_arguments)]
+                pub fn new_tuple(_0: u32, _1: u64) -> Self {
+                    <Self as core::convert::From<Tuple>>::from(<Tuple>::new(_0, _1),)
+                }
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn rejects_tuple_conv_with_conflicting_enum_impls() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(tuple_conv)]
+            pub enum MyEnum {
+                Tuple(u32, Vec<u8>),
+                Struct { a: u32, b: Vec<u8> },
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "`#[evt(tuple_conv)]` generates overlapping `From<(..)>` impls for `MyEnum` from both `Tuple` and `Struct`, as their fields may have the same types."
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn rejects_tuple_conv_with_generic_fields_that_may_overlap() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(tuple_conv)]
+            pub enum MyEnum<T, U> {
+                A(T),
+                B(U),
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "`#[evt(tuple_conv)]` generates overlapping `From<(..)>` impls for `MyEnum` from both `A` and `B`, as their fields may have the same types."
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn rejects_tuple_conv_with_const_generic_fields_that_may_overlap() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(tuple_conv)]
+            pub enum MyEnum<const N: usize> {
+                A([u8; N], u32),
+                B([u8; 4], u32),
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "`#[evt(tuple_conv)]` generates overlapping `From<(..)>` impls for `MyEnum` from both `A` and `B`, as their fields may have the same types."
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn generates_map_methods_for_variants_with_conversions() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(map, impls(try_from))]
+            pub enum MyEnum {
+                A(u8),
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            impl core::convert::TryFrom<MyEnum> for A {
+                type Error = MyEnum;
+
+                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+                    // Deconstruct the variant.
+                    if let MyEnum::A(_0,) = enum_variant {
+                        core::result::Result::Ok(A(_0,))
+                    } else {
+                        core::result::Result::Err(enum_variant)
+                    }
+                }
+            }
+
+            impl MyEnum {
+                #[doc = "Replaces this with the closure's result if this is `MyEnum::A`, otherwise returns `self` unchanged."]
+                pub fn and_then_a(self, f: impl core::ops::FnOnce(A) -> Self) -> Self {
+                    match <A as core::convert::TryFrom<Self>>::try_from(self) {
+                        core::result::Result::Ok(variant_struct) => f(variant_struct),
+                        core::result::Result::Err(enum_variant) => enum_variant,
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn rejects_buckets_with_variant_named_rest() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(buckets)]
+            pub enum MyEnum {
+                A,
+                Rest(u32),
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "`#[evt(buckets)]` cannot generate the `rest` bucket for `Rest`, as `MyEnumBuckets::rest` holds variants without a generated struct."
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn rejects_builder_with_reserved_field_name() {
+        let ast: DeriveInput = parse_quote! {
+            #[evt(builder)]
+            pub enum MyEnum {
+                Config { build: u32 },
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "`#[evt(builder)]` cannot generate a setter for the field `build` of `Config`, as it clashes with `ConfigBuilder::build`."
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+
+    #[test]
+    fn rejects_kind_set_with_more_than_128_variants() {
+        let variants = (0..129u32).map(|index| quote::format_ident!("V{}", index));
+        let ast: DeriveInput = parse_quote! {
+            #[evt(kind_set)]
+            pub enum MyEnum {
+                #(#variants,)*
+            }
+        };
+
+        let actual_tokens = enum_variant_type_impl(ast);
+        let expected_tokens = quote! {
+            ::core::compile_error! {
+                "`#[evt(kind_set)]` supports enums with at most 128 variants."
+            }
+        };
+
+        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
+    }
+}
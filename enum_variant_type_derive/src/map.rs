@@ -0,0 +1,70 @@
+use alloc::string::ToString;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Generics, Visibility};
+
+use crate::{generated_variant::GeneratedVariant, snake_case};
+
+/// Returns the `map_<variant>` and `and_then_<variant>` methods for the enum.
+///
+/// These are built on the `From` and `TryFrom` impls, so are only generated
+/// for variants that have them.
+pub(crate) fn enum_tokens(
+    enum_name: &Ident,
+    vis: &Visibility,
+    generics: &Generics,
+    generated_variants: &[Option<GeneratedVariant>],
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let methods = generated_variants
+        .iter()
+        .flatten()
+        .filter(|generated_variant| generated_variant.impls.try_from)
+        .map(|generated_variant| {
+            let variant_name = &generated_variant.variant.ident;
+            let snake_case = snake_case::snake_case(&variant_name.to_string());
+            let map_fn = format_ident!("map_{}", snake_case);
+            let and_then_fn = format_ident!("and_then_{}", snake_case);
+            let struct_ty = generated_variant.struct_ty(&ty_generics);
+            let map_doc = alloc::format!(
+                "Transforms the fields if this is `{enum_name}::{variant_name}`, otherwise \
+                    returns `self` unchanged."
+            );
+            let and_then_doc = alloc::format!(
+                "Replaces this with the closure's result if this is \
+                    `{enum_name}::{variant_name}`, otherwise returns `self` unchanged."
+            );
+
+            let map = generated_variant.impls.from.then(|| {
+                quote! {
+                    #[doc = #map_doc]
+                    #vis fn #map_fn(self, f: impl core::ops::FnOnce(#struct_ty) -> #struct_ty) -> Self {
+                        match <#struct_ty as core::convert::TryFrom<Self>>::try_from(self) {
+                            core::result::Result::Ok(variant_struct) => {
+                                <Self as core::convert::From<#struct_ty>>::from(f(variant_struct))
+                            }
+                            core::result::Result::Err(enum_variant) => enum_variant,
+                        }
+                    }
+                }
+            });
+
+            quote! {
+                #map
+
+                #[doc = #and_then_doc]
+                #vis fn #and_then_fn(self, f: impl core::ops::FnOnce(#struct_ty) -> Self) -> Self {
+                    match <#struct_ty as core::convert::TryFrom<Self>>::try_from(self) {
+                        core::result::Result::Ok(variant_struct) => f(variant_struct),
+                        core::result::Result::Err(enum_variant) => enum_variant,
+                    }
+                }
+            }
+        });
+
+    quote! {
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            #(#methods)*
+        }
+    }
+}
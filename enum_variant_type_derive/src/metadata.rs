@@ -0,0 +1,121 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{ext::IdentExt, punctuated::Punctuated, Fields, Generics, Member, Token, Type, Variant};
+
+use crate::{generated_variant::GeneratedVariant, variant_fields::VariantFields};
+
+/// Returns the `VARIANT_NAME`, `VARIANT_INDEX`, `FIELD_NAMES`, and
+/// `FIELD_TYPES` constants for a variant's struct.
+///
+/// The field constants describe the generated struct, so renamed fields use
+/// their new name, and skipped fields are left out.
+pub(crate) fn struct_tokens(
+    variant_name: &Ident,
+    variant_index: usize,
+    generics: &Generics,
+    variant_fields: &VariantFields,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let (impl_generics, ty_generics, where_clause) = if variant_fields.is_unit() {
+        (None, None, None)
+    } else {
+        (Some(impl_generics), Some(ty_generics), where_clause)
+    };
+    let variant_name_str = variant_name.unraw().to_string();
+    let field_names = variant_fields
+        .struct_members()
+        .map(|member| member_name(&member));
+    let field_types = variant_fields
+        .struct_fields()
+        .map(|field| type_name(&field.ty));
+
+    quote! {
+        impl #impl_generics #variant_name #ty_generics #where_clause {
+            /// Name of the variant this struct is generated from.
+            pub const VARIANT_NAME: &'static str = #variant_name_str;
+            /// Position of the variant in the enum's declaration.
+            pub const VARIANT_INDEX: usize = #variant_index;
+            /// Names of this struct's fields.
+            pub const FIELD_NAMES: &'static [&'static str] = &[#(#field_names),*];
+            /// Types of this struct's fields, formatted by `stringify!`.
+            pub const FIELD_TYPES: &'static [&'static str] = &[#(#field_types),*];
+        }
+    }
+}
+
+/// Returns the `VARIANT_COUNT` and `VARIANTS` constants for the enum.
+///
+/// # Parameters
+///
+/// * `variants`: Each variant of the enum.
+/// * `generated_variants`: Items generated for each variant, if any.
+pub(crate) fn enum_tokens(
+    enum_name: &Ident,
+    generics: &Generics,
+    variants: &Punctuated<Variant, Token![,]>,
+    generated_variants: &[Option<GeneratedVariant>],
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let variant_count = variants.len();
+    let variant_infos = variants.iter().zip(generated_variants).enumerate().map(
+        |(index, (variant, generated_variant))| {
+            let has_struct = generated_variant
+                .as_ref()
+                .is_some_and(|generated_variant| generated_variant.impls.r#struct);
+            let name = variant.ident.unraw().to_string();
+            let field_names = variant_field_names(&variant.fields);
+            let field_types = variant.fields.iter().map(|field| type_name(&field.ty));
+
+            quote! {
+                ::enum_variant_type::VariantInfo {
+                    name: #name,
+                    index: #index,
+                    field_names: &[#(#field_names),*],
+                    field_types: &[#(#field_types),*],
+                    has_struct: #has_struct,
+                }
+            }
+        },
+    );
+
+    quote! {
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            /// Number of variants in this enum.
+            pub const VARIANT_COUNT: usize = #variant_count;
+            /// Metadata about each variant in this enum.
+            pub const VARIANTS: &'static [::enum_variant_type::VariantInfo] = &[
+                #(#variant_infos),*
+            ];
+        }
+    }
+}
+
+/// Returns the name of each field, or its index for tuple fields.
+fn variant_field_names(fields: &Fields) -> Vec<String> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => ident.unraw().to_string(),
+            None => format!("{index}"),
+        })
+        .collect()
+}
+
+/// Returns the name of a struct field, or its index for tuple fields.
+fn member_name(member: &Member) -> String {
+    match member {
+        Member::Named(ident) => ident.unraw().to_string(),
+        Member::Unnamed(index) => format!("{}", index.index),
+    }
+}
+
+/// Returns `stringify!(ty)`, so the type name is formatted by rustc.
+fn type_name(ty: &Type) -> TokenStream {
+    quote!(core::stringify!(#ty))
+}
@@ -0,0 +1,294 @@
+use alloc::vec::Vec;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{
+    meta::ParseNestedMeta, parenthesized, parse::Parser, punctuated::Punctuated, token, LitStr,
+    Meta, Path, Token, UseTree, Visibility,
+};
+
+/// Parameters for the module that generated structs are placed in.
+///
+/// Parsed from either of:
+///
+/// * `#[evt(module)]`
+/// * `#[evt(module = "a::b")]`
+/// * `#[evt(module(name = "a::b", vis = "pub(crate)", attrs(..), imports(..),
+///   reexport))]`
+#[derive(Debug, Default)]
+pub(crate) struct ModuleParams {
+    /// Path segments of the module, `None` to derive it from the enum name.
+    pub(crate) name: Option<Vec<Ident>>,
+    /// Visibility of the module, `None` to use the enum's visibility.
+    pub(crate) vis: Option<Visibility>,
+    /// Attributes to attach to the module containing the structs.
+    pub(crate) attrs: Vec<Meta>,
+    /// Items to import into the module, `None` to import `super::*`.
+    pub(crate) imports: Option<Vec<UseTree>>,
+    /// Whether to re-export the generated structs alongside the enum.
+    pub(crate) reexport: bool,
+}
+
+impl ModuleParams {
+    /// Parses the `module` parameter of an `evt` attribute.
+    pub(crate) fn parse(nested_meta: &ParseNestedMeta) -> syn::Result<Self> {
+        let mut module_params = ModuleParams::default();
+
+        if nested_meta.input.peek(Token![=]) {
+            // `#[evt(module = "a::b")]`
+            let module_name: LitStr = nested_meta.value()?.parse()?;
+            module_params.name = Some(module_path_segments(&module_name)?);
+        } else if nested_meta.input.peek(token::Paren) {
+            // `#[evt(module(name = "a::b", ..))]`
+            nested_meta.parse_nested_meta(|module_meta| {
+                if module_meta.path.is_ident("name") {
+                    let module_name: LitStr = module_meta.value()?.parse()?;
+                    module_params.name = Some(module_path_segments(&module_name)?);
+                } else if module_meta.path.is_ident("vis") {
+                    let vis: LitStr = module_meta.value()?.parse()?;
+                    module_params.vis = Some(vis.parse()?);
+                } else if module_meta.path.is_ident("attrs") {
+                    let content;
+                    parenthesized!(content in module_meta.input);
+                    module_params
+                        .attrs
+                        .extend(Punctuated::<Meta, Token![,]>::parse_terminated(&content)?);
+                } else if module_meta.path.is_ident("imports") {
+                    let content;
+                    parenthesized!(content in module_meta.input);
+                    module_params.imports = Some(
+                        Punctuated::<UseTree, Token![,]>::parse_terminated(&content)?
+                            .into_iter()
+                            .collect(),
+                    );
+                } else if module_meta.path.is_ident("reexport") {
+                    module_params.reexport = true;
+                } else {
+                    return Err(module_meta.error(
+                        "Expected one of `name`, `vis`, `attrs`, `imports`, or `reexport` \
+                            in `#[evt(module(..))]`.",
+                    ));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(module_params)
+    }
+
+    /// Returns the path segments of the module.
+    ///
+    /// If no name was specified, this is the enum name in `snake_case`.
+    pub(crate) fn segments(&self, enum_name: &Ident) -> Vec<Ident> {
+        self.name
+            .clone()
+            .unwrap_or_else(|| alloc::vec![crate::snake_case::snake_case_ident(enum_name)])
+    }
+
+    /// Returns the `use` statements for a module that is `depth` levels below
+    /// the enum.
+    fn imports(&self, enum_name: &Ident, depth: usize) -> TokenStream {
+        let supers = (0..depth)
+            .map(|_| Ident::new("super", Span::call_site()))
+            .collect::<Vec<Ident>>();
+
+        match &self.imports {
+            Some(imports) => quote! {
+                use #(#supers::)* #enum_name;
+                #(use #imports;)*
+            },
+            None => quote! {
+                use #(#supers::)* *;
+            },
+        }
+    }
+}
+
+/// Generated struct declarations, grouped by the module they are placed in.
+///
+/// Each distinct module path is emitted once, with the declarations of every
+/// variant placed in that module.
+#[derive(Debug, Default)]
+pub(crate) struct ModuleTree<'a> {
+    /// Names of the structs declared directly in this module.
+    struct_names: Vec<&'a Ident>,
+    /// Struct declarations and impls directly in this module.
+    declarations: TokenStream,
+    /// Child modules, in the order they were first inserted.
+    children: Vec<(Ident, ModuleTree<'a>)>,
+}
+
+impl<'a> ModuleTree<'a> {
+    /// Inserts the declarations of a struct into the module at `path`.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Module path relative to the enum, empty for the enum's module.
+    /// * `struct_name`: Name of the generated struct, used when re-exporting.
+    ///   `None` if the struct itself is not generated.
+    /// * `declarations`: The struct declaration and its impls.
+    pub(crate) fn insert(
+        &mut self,
+        path: &[Ident],
+        struct_name: Option<&'a Ident>,
+        declarations: TokenStream,
+    ) {
+        let node = self.node_mut(path);
+        node.struct_names.extend(struct_name);
+        node.declarations.extend(declarations);
+    }
+
+    /// Returns the node at `path`, inserting empty nodes as necessary.
+    pub(crate) fn node_mut(&mut self, path: &[Ident]) -> &mut Self {
+        path.iter().fold(self, |node, segment| {
+            let index = match node.children.iter().position(|(name, _)| name == segment) {
+                Some(index) => index,
+                None => {
+                    node.children.push((segment.clone(), ModuleTree::default()));
+                    node.children.len() - 1
+                }
+            };
+            &mut node.children[index].1
+        })
+    }
+
+    /// Returns the tokens of every module and the declarations within them.
+    ///
+    /// # Parameters
+    ///
+    /// * `module_params`: Parameters of the enum level module.
+    /// * `enum_name`: Name of the enum the structs are generated from.
+    /// * `enum_vis`: Visibility of the enum.
+    pub(crate) fn into_tokens(
+        self,
+        module_params: Option<&ModuleParams>,
+        enum_name: &Ident,
+        enum_vis: &Visibility,
+    ) -> TokenStream {
+        let enum_module_path = module_params
+            .map(|module_params| module_params.segments(enum_name))
+            .unwrap_or_default();
+        let module_params_default = ModuleParams::default();
+        let module_params = module_params.unwrap_or(&module_params_default);
+        let mut reexports = Vec::new();
+
+        let mut tokens = self.module_tokens(
+            &[],
+            module_params,
+            enum_name,
+            enum_vis,
+            &enum_module_path,
+            &mut reexports,
+        );
+
+        if module_params.reexport {
+            tokens.extend(reexports.into_iter().map(|(path, struct_names)| {
+                quote! {
+                    #enum_vis use #(#path)::* :: { #(#struct_names),* };
+                }
+            }));
+        }
+
+        tokens
+    }
+
+    /// Returns the contents of the module at `path`, including child modules.
+    fn module_tokens(
+        self,
+        path: &[Ident],
+        module_params: &ModuleParams,
+        enum_name: &Ident,
+        enum_vis: &Visibility,
+        enum_module_path: &[Ident],
+        reexports: &mut Vec<(Vec<Ident>, Vec<&'a Ident>)>,
+    ) -> TokenStream {
+        let ModuleTree {
+            struct_names,
+            declarations,
+            children,
+        } = self;
+
+        let imports = if path.is_empty() || declarations.is_empty() {
+            TokenStream::new()
+        } else {
+            module_params.imports(enum_name, path.len())
+        };
+        if !path.is_empty() && !struct_names.is_empty() {
+            reexports.push((path.to_vec(), struct_names));
+        }
+
+        let vis = module_params.vis.as_ref().unwrap_or(enum_vis);
+        let child_modules = children
+            .into_iter()
+            .map(|(segment, child)| {
+                let mut child_path = path.to_vec();
+                child_path.push(segment.clone());
+
+                let attrs = if child_path == enum_module_path {
+                    module_params.attrs.as_slice()
+                } else {
+                    &[]
+                };
+                let child_tokens = child.module_tokens(
+                    &child_path,
+                    module_params,
+                    enum_name,
+                    enum_vis,
+                    enum_module_path,
+                    reexports,
+                );
+
+                quote! {
+                    #(#[#attrs])*
+                    #vis mod #segment {
+                        #child_tokens
+                    }
+                }
+            })
+            .collect::<Vec<TokenStream>>();
+
+        quote! {
+            #imports
+
+            #declarations
+
+            #(#child_modules)*
+        }
+    }
+}
+
+/// Parses a module path such as `"a::b"` or `"r#type"` into its segments.
+pub(crate) fn module_path_segments(module_name: &LitStr) -> syn::Result<Vec<Ident>> {
+    let path = Path::parse_mod_style
+        .parse_str(&module_name.value())
+        .map_err(|e| {
+            syn::Error::new(
+                module_name.span(),
+                alloc::format!("Invalid module path `{}`: {}", module_name.value(), e),
+            )
+        })?;
+
+    if path.leading_colon.is_some() {
+        return Err(syn::Error::new(
+            module_name.span(),
+            "Module path must be relative to the enum, and not start with `::`.",
+        ));
+    }
+
+    path.segments
+        .into_iter()
+        .map(|segment| {
+            if ["self", "super", "crate", "Self"]
+                .iter()
+                .any(|keyword| segment.ident == keyword)
+            {
+                Err(syn::Error::new(
+                    module_name.span(),
+                    alloc::format!("`{}` cannot be used as a module name.", segment.ident),
+                ))
+            } else {
+                Ok(segment.ident)
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,157 @@
+use alloc::vec::Vec;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{meta::ParseNestedMeta, parenthesized, LitInt, MetaList, Token};
+
+/// Primitive representations, which are only valid on enums.
+const INT_REPRS: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// Parsed `#[repr(..)]` hints.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Repr {
+    /// `repr(C)`.
+    pub(crate) c: Option<Ident>,
+    /// `repr(transparent)`.
+    pub(crate) transparent: Option<Ident>,
+    /// `repr(u8)`, `repr(i32)`, etc.
+    pub(crate) int: Option<Ident>,
+    /// `repr(align(N))`.
+    pub(crate) align: Option<LitInt>,
+    /// `repr(packed)` or `repr(packed(N))`.
+    pub(crate) packed: Option<(Ident, Option<LitInt>)>,
+}
+
+impl Repr {
+    /// Parses the hints from `repr(..)`, adding them to this `Repr`.
+    pub(crate) fn parse_meta_list(&mut self, meta_list: &MetaList) -> syn::Result<()> {
+        meta_list.parse_nested_meta(|nested_meta| self.parse_hint(nested_meta))
+    }
+
+    /// Parses a single hint within `repr(..)`.
+    fn parse_hint(&mut self, nested_meta: ParseNestedMeta) -> syn::Result<()> {
+        let ident = nested_meta
+            .path
+            .get_ident()
+            .cloned()
+            .ok_or_else(|| nested_meta.error("Unrecognized `repr` hint."))?;
+
+        if ident == "C" {
+            self.c = Some(ident);
+        } else if ident == "transparent" {
+            self.transparent = Some(ident);
+        } else if INT_REPRS.iter().any(|int_repr| ident == int_repr) {
+            self.int = Some(ident);
+        } else if ident == "align" {
+            let content;
+            parenthesized!(content in nested_meta.input);
+            self.align = Some(content.parse()?);
+        } else if ident == "packed" {
+            let n = if nested_meta.input.is_empty() || nested_meta.input.peek(Token![,]) {
+                None
+            } else {
+                let content;
+                parenthesized!(content in nested_meta.input);
+                Some(content.parse()?)
+            };
+            self.packed = Some((ident, n));
+        } else {
+            return Err(nested_meta.error(alloc::format!("Unrecognized `repr` hint `{ident}`.")));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the hints of an enum's `repr` that apply to the generated
+    /// structs.
+    ///
+    /// Primitive representations such as `repr(u8)` are not valid on structs,
+    /// and are dropped.
+    pub(crate) fn struct_repr(&self) -> Repr {
+        Repr {
+            int: None,
+            ..self.clone()
+        }
+    }
+
+    /// Returns whether the only hint is `repr(C)`, optionally with a primitive
+    /// representation.
+    pub(crate) fn is_c_only(&self) -> bool {
+        self.c.is_some()
+            && self.transparent.is_none()
+            && self.align.is_none()
+            && self.packed.is_none()
+    }
+
+    /// Returns an error if these hints cannot be applied to the struct
+    /// generated for a variant.
+    ///
+    /// # Parameters
+    ///
+    /// * `field_count`: Number of fields in the generated struct.
+    pub(crate) fn validate_for_struct(&self, field_count: usize) -> syn::Result<()> {
+        if let Some(int) = &self.int {
+            return Err(syn::Error::new(
+                int.span(),
+                alloc::format!(
+                    "`repr({int})` cannot be used on the generated struct, primitive \
+                        representations are only valid on enums."
+                ),
+            ));
+        }
+
+        if let Some(transparent) = &self.transparent {
+            if self.c.is_some() || self.align.is_some() || self.packed.is_some() {
+                return Err(syn::Error::new(
+                    transparent.span(),
+                    "`repr(transparent)` cannot be combined with other `repr` hints.",
+                ));
+            }
+            if field_count != 1 {
+                return Err(syn::Error::new(
+                    transparent.span(),
+                    "`repr(transparent)` can only be used on variants with a single field.",
+                ));
+            }
+        }
+
+        if let (Some((packed, _)), Some(_)) = (&self.packed, &self.align) {
+            return Err(syn::Error::new(
+                packed.span(),
+                "`repr(packed)` cannot be combined with `repr(align(..))`.",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the `#[repr(..)]` attribute for these hints, or nothing if there
+    /// are none.
+    pub(crate) fn to_attr(&self) -> TokenStream {
+        let mut hints = Vec::<TokenStream>::new();
+        if let Some(c) = &self.c {
+            hints.push(quote!(#c));
+        }
+        if let Some(transparent) = &self.transparent {
+            hints.push(quote!(#transparent));
+        }
+        if let Some(int) = &self.int {
+            hints.push(quote!(#int));
+        }
+        match &self.packed {
+            Some((packed, Some(n))) => hints.push(quote!(#packed(#n))),
+            Some((packed, None)) => hints.push(quote!(#packed)),
+            None => {}
+        }
+        if let Some(align) = &self.align {
+            hints.push(quote!(align(#align)));
+        }
+
+        if hints.is_empty() {
+            TokenStream::new()
+        } else {
+            quote!(#[repr(#(#hints),*)])
+        }
+    }
+}
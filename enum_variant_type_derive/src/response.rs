@@ -0,0 +1,142 @@
+use alloc::vec::Vec;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, Attribute, Generics, Type, Visibility};
+
+use crate::generated_variant::GeneratedVariant;
+
+/// Returns the name of the response enum, e.g. `MyEnumResponse`.
+fn response_name(enum_name: &Ident) -> Ident {
+    format_ident!("{}Response", enum_name.unraw())
+}
+
+/// Returns the `MyEnumResponse` enum, the `Request` impl for each struct with
+/// a `#[evt(response = Type)]`, and `MyEnum::call`.
+///
+/// Nothing is generated if no variant has a response.
+///
+/// # Parameters
+///
+/// * `response_derive`: The `#[evt(response_derive(..))]` derives for
+///   `MyEnumResponse`, which has no derives without it.
+pub(crate) fn enum_tokens(
+    enum_name: &Ident,
+    vis: &Visibility,
+    generics: &Generics,
+    response_derive: Option<&Attribute>,
+    generated_variants: &[Option<GeneratedVariant>],
+) -> syn::Result<TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let requests = generated_variants
+        .iter()
+        .flatten()
+        .filter_map(|generated_variant| {
+            generated_variant
+                .response
+                .as_ref()
+                .map(|response_ty| (generated_variant, response_ty))
+        })
+        .collect::<Vec<(&GeneratedVariant, &Type)>>();
+    if requests.is_empty() {
+        return Ok(TokenStream::new());
+    }
+
+    let response_name = response_name(enum_name);
+    let mut response_variants = Vec::<TokenStream>::new();
+    let mut response_impls = Vec::<TokenStream>::new();
+    let mut response_tys = Vec::<(&Type, &Ident)>::new();
+    for (generated_variant, response_ty) in requests {
+        let variant_name = &generated_variant.variant.ident;
+        if !(generated_variant.impls.r#struct && generated_variant.impls.from) {
+            return Err(syn::Error::new(
+                variant_name.span(),
+                alloc::format!(
+                    "`#[evt(response = ..)]` requires a struct and `From` impl for \
+                        `{variant_name}`, but they are not both generated."
+                ),
+            ));
+        }
+
+        if let Some((_, existing_name)) = response_tys
+            .iter()
+            .find(|(existing_ty, _)| *existing_ty == response_ty)
+        {
+            return Err(syn::Error::new(
+                variant_name.span(),
+                alloc::format!(
+                    "The response type of `{variant_name}` is also the response type of \
+                        `{existing_name}`. Each variant needs a distinct response type."
+                ),
+            ));
+        }
+        response_tys.push((response_ty, variant_name));
+
+        let struct_ty = generated_variant.struct_ty(&ty_generics);
+        let impl_generics = (!generated_variant.fields.is_unit()).then_some(&impl_generics);
+        let struct_where_clause = (!generated_variant.fields.is_unit()).then_some(where_clause);
+        let doc = alloc::format!("Response to [`{enum_name}::{variant_name}`].");
+
+        response_variants.push(quote! {
+            #[doc = #doc]
+            #variant_name(#response_ty)
+        });
+        response_impls.push(quote! {
+            impl core::convert::From<#response_ty> for #response_name {
+                fn from(response: #response_ty) -> Self {
+                    #response_name::#variant_name(response)
+                }
+            }
+
+            impl core::convert::TryFrom<#response_name> for #response_ty {
+                type Error = #response_name;
+
+                fn try_from(response: #response_name) -> core::result::Result<Self, Self::Error> {
+                    match response {
+                        #response_name::#variant_name(response) => core::result::Result::Ok(response),
+                        #[allow(unreachable_patterns)]
+                        response => core::result::Result::Err(response),
+                    }
+                }
+            }
+
+            impl #impl_generics ::enum_variant_type::Request for #struct_ty #struct_where_clause {
+                type Enum = #enum_name #ty_generics;
+                type Response = #response_ty;
+            }
+        });
+    }
+    let doc = alloc::format!(
+        "Responses to [`{enum_name}`] requests, with one variant per `#[evt(response = ..)]`."
+    );
+
+    Ok(quote! {
+        #[doc = #doc]
+        #response_derive
+        #vis enum #response_name {
+            #(#response_variants,)*
+        }
+
+        #(#response_impls)*
+
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            /// Sends `request` through `transport`, and converts the response
+            /// into the request's response type.
+            ///
+            /// Returns the response in `Err` if `transport` answers with a
+            /// response to a different request. This returns a `Result`
+            /// instead of `R::Response`, so that a misbehaving transport is
+            /// handled by the caller instead of panicking.
+            #vis fn call<R>(
+                request: R,
+                transport: impl core::ops::FnOnce(Self) -> #response_name,
+            ) -> core::result::Result<R::Response, #response_name>
+            where
+                R: ::enum_variant_type::Request<Enum = Self>,
+                R::Response: core::convert::TryFrom<#response_name, Error = #response_name>,
+            {
+                let response = transport(request.into());
+                <R::Response as core::convert::TryFrom<#response_name>>::try_from(response)
+            }
+        }
+    })
+}
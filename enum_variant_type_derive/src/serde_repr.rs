@@ -0,0 +1,330 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use proc_macro2::{Group, Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{
+    ext::IdentExt, meta::ParseNestedMeta, parse_quote, punctuated::Punctuated, token, Attribute,
+    Expr, Field, Fields, Generics, LitStr, Token, Variant,
+};
+
+use crate::variant_fields::VariantFields;
+
+/// Returns the path to the `serde` crate re-exported by `enum_variant_type`.
+fn serde_path() -> syn::Path {
+    parse_quote!(::enum_variant_type::__private::serde)
+}
+
+/// How the enum's variants are tagged, from `#[evt(serde_repr = "..")]`.
+#[derive(Debug, PartialEq)]
+enum Tagging {
+    /// `"external"`: `{"Variant": {..}}`.
+    External,
+    /// `"internal(tag)"`: `{"tag": "Variant", ..}`.
+    Internal { tag: String },
+    /// `"adjacent(tag, content)"`: `{"tag": "Variant", "content": {..}}`.
+    Adjacent { tag: String, content: String },
+}
+
+/// Serde representation of the generated structs, so that they serialize
+/// exactly like their enum variant.
+#[derive(Debug)]
+pub(crate) struct SerdeRepr {
+    /// `#[serde(..)]` attributes for the enum that mirrors the original enum.
+    container_attrs: TokenStream,
+}
+
+impl SerdeRepr {
+    /// Parses `#[evt(serde_repr = "..")]`, and checks it against the enum's
+    /// own `#[serde(..)]` attributes.
+    ///
+    /// The enum's `rename` and `rename_all` attributes are carried over. If the
+    /// enum has any `#[serde(..)]` attributes, its `tag` and `content` must
+    /// match the given representation.
+    pub(crate) fn new(
+        serde_repr: &LitStr,
+        enum_name: &Ident,
+        enum_attrs: &[Attribute],
+    ) -> syn::Result<Self> {
+        let tagging = Tagging::parse(serde_repr)?;
+
+        let mut has_serde_attrs = false;
+        let mut has_rename = false;
+        let mut renames = Vec::<TokenStream>::new();
+        let mut enum_tag = None::<String>;
+        let mut enum_content = None::<String>;
+        let mut untagged = false;
+        for attr in enum_attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("serde"))
+        {
+            has_serde_attrs = true;
+            attr.parse_nested_meta(|nested_meta| {
+                let path = &nested_meta.path;
+                if path.is_ident("rename") || path.is_ident("rename_all") {
+                    has_rename |= path.is_ident("rename");
+                    let value = meta_value(&nested_meta)?;
+                    renames.push(quote!(#path #value));
+                } else if path.is_ident("tag") {
+                    enum_tag = Some(nested_meta.value()?.parse::<LitStr>()?.value());
+                } else if path.is_ident("content") {
+                    enum_content = Some(nested_meta.value()?.parse::<LitStr>()?.value());
+                } else {
+                    untagged |= path.is_ident("untagged");
+                    meta_value(&nested_meta)?;
+                }
+                Ok(())
+            })?;
+        }
+
+        if has_serde_attrs {
+            let enum_tagging = match (enum_tag, enum_content) {
+                _ if untagged => None,
+                (None, _) => Some(Tagging::External),
+                (Some(tag), None) => Some(Tagging::Internal { tag }),
+                (Some(tag), Some(content)) => Some(Tagging::Adjacent { tag, content }),
+            };
+            if enum_tagging.as_ref() != Some(&tagging) {
+                return Err(syn::Error::new_spanned(
+                    serde_repr,
+                    alloc::format!(
+                        "`#[evt(serde_repr = \"{}\")]` does not match the `#[serde(..)]` \
+                            tagging of `{enum_name}`.",
+                        serde_repr.value()
+                    ),
+                ));
+            }
+        }
+
+        let serde_crate = serde_path().to_token_stream().to_string();
+        let mut container_attrs = quote!(#[serde(crate = #serde_crate)]);
+        if !has_rename {
+            let enum_name = enum_name.unraw().to_string();
+            container_attrs.extend(quote!(#[serde(rename = #enum_name)]));
+        }
+        container_attrs.extend(renames.iter().map(|rename| quote!(#[serde(#rename)])));
+        container_attrs.extend(match &tagging {
+            Tagging::External => TokenStream::new(),
+            Tagging::Internal { tag } => quote!(#[serde(tag = #tag)]),
+            Tagging::Adjacent { tag, content } => quote!(#[serde(tag = #tag, content = #content)]),
+        });
+
+        Ok(SerdeRepr { container_attrs })
+    }
+
+    /// Returns the `Serialize` and `Deserialize` impls for a variant's struct.
+    ///
+    /// Both delegate to an enum with the same variants and `#[serde(..)]`
+    /// attributes as the original enum, so the variant's name, index, and
+    /// tagging are the same. Other variants are unit variants that are never
+    /// serialized, and fail to deserialize into the struct.
+    pub(crate) fn struct_tokens(
+        &self,
+        enum_name: &Ident,
+        variants: &Punctuated<Variant, Token![,]>,
+        variant_index: usize,
+        generics: &Generics,
+        variant_fields: &VariantFields,
+    ) -> TokenStream {
+        let serde = serde_path();
+        let container_attrs = &self.container_attrs;
+        let variant = &variants[variant_index];
+        let variant_name = &variant.ident;
+        let variants_before = variants
+            .iter()
+            .take(variant_index)
+            .map(|variant| &variant.ident);
+        let variants_after = variants
+            .iter()
+            .skip(variant_index + 1)
+            .map(|variant| &variant.ident);
+        let variants_before = quote!(#(#variants_before,)*);
+        let variants_after = quote!(#(#variants_after,)*);
+        let variant_attrs = serde_attrs(&variant.attrs).collect::<Vec<&Attribute>>();
+        let struct_form = variant_fields.struct_form();
+        let expected = alloc::format!("expected the `{enum_name}::{variant_name}` variant");
+
+        let fields = variant_fields
+            .kept_enum_fields(&variant.fields)
+            .collect::<Vec<(&Field, &Ident)>>();
+        let mirror_fields = |ref_lifetime: Option<TokenStream>| {
+            let field_tokens = fields.iter().map(|(field, _)| {
+                let attrs = serde_attrs(&field.attrs);
+                let field_name = field.ident.iter();
+                let ty = &field.ty;
+                quote!(#(#attrs)* #(#field_name:)* #ref_lifetime #ty)
+            });
+            mirror_form(&variant.fields, field_tokens)
+        };
+        let ser_fields = mirror_fields(Some(quote!(&'evt)));
+        let de_fields = mirror_fields(None);
+        let mirror_values = mirror_form(
+            &variant.fields,
+            fields.iter().map(|(_, binding)| quote!(#binding)),
+        );
+
+        let is_unit = variant_fields.is_unit();
+        let mut ser_generics = if is_unit {
+            Generics::default()
+        } else {
+            generics.clone()
+        };
+        let mut de_generics = ser_generics.clone();
+        let (mirror_impl_generics, mirror_ty_generics, mirror_where_clause) =
+            de_generics.split_for_impl();
+        let mirror_decl_generics = quote!(#mirror_impl_generics);
+        let mirror_ty_generics = quote!(#mirror_ty_generics);
+        let mirror_where_clause = quote!(#mirror_where_clause);
+        let ser_mirror_generics = {
+            let mut ser_mirror_generics = ser_generics.clone();
+            if !is_unit {
+                ser_mirror_generics.params.insert(0, parse_quote!('evt));
+            }
+            let (ser_mirror_generics, _, _) = ser_mirror_generics.split_for_impl();
+            quote!(#ser_mirror_generics)
+        };
+        let type_params = generics
+            .type_params()
+            .map(|type_param| type_param.ident.clone())
+            .collect::<Vec<Ident>>();
+        if !is_unit {
+            let ser_where_clause = ser_generics.make_where_clause();
+            let de_where_clause = de_generics.make_where_clause();
+            type_params.iter().for_each(|type_param| {
+                ser_where_clause
+                    .predicates
+                    .push(parse_quote!(#type_param: #serde::Serialize));
+                de_where_clause
+                    .predicates
+                    .push(parse_quote!(#type_param: #serde::Deserialize<'de>));
+            });
+        }
+        let (ser_impl_generics, ty_generics, ser_where_clause) = ser_generics.split_for_impl();
+        let de_where_clause = &de_generics.where_clause;
+        de_generics.params.insert(0, parse_quote!('de));
+        let (de_impl_generics, _, _) = de_generics.split_for_impl();
+
+        quote! {
+            impl #ser_impl_generics #serde::Serialize for #variant_name #ty_generics #ser_where_clause {
+                fn serialize<__S>(
+                    &self,
+                    serializer: __S,
+                ) -> core::result::Result<__S::Ok, __S::Error>
+                where
+                    __S: #serde::Serializer,
+                {
+                    #[derive(#serde::Serialize)]
+                    #container_attrs
+                    #[allow(dead_code)]
+                    enum __EvtSerialize #ser_mirror_generics #mirror_where_clause {
+                        #variants_before
+                        #(#variant_attrs)*
+                        #variant_name #ser_fields,
+                        #variants_after
+                    }
+
+                    let Self #struct_form = self;
+                    #serde::Serialize::serialize(
+                        &__EvtSerialize::#variant_name #mirror_values,
+                        serializer,
+                    )
+                }
+            }
+
+            impl #de_impl_generics #serde::Deserialize<'de> for #variant_name #ty_generics #de_where_clause {
+                fn deserialize<__D>(deserializer: __D) -> core::result::Result<Self, __D::Error>
+                where
+                    __D: #serde::Deserializer<'de>,
+                {
+                    #[derive(#serde::Deserialize)]
+                    #container_attrs
+                    enum __EvtDeserialize #mirror_decl_generics #mirror_where_clause {
+                        #variants_before
+                        #(#variant_attrs)*
+                        #variant_name #de_fields,
+                        #variants_after
+                    }
+
+                    match <__EvtDeserialize #mirror_ty_generics as #serde::Deserialize>::deserialize(
+                        deserializer,
+                    )? {
+                        __EvtDeserialize::#variant_name #mirror_values => {
+                            core::result::Result::Ok(Self #struct_form)
+                        }
+                        #[allow(unreachable_patterns)]
+                        _ => core::result::Result::Err(
+                            <__D::Error as #serde::de::Error>::custom(#expected),
+                        ),
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Tagging {
+    /// Parses `"external"`, `"internal(tag)"`, or `"adjacent(tag, content)"`.
+    fn parse(serde_repr: &LitStr) -> syn::Result<Self> {
+        let value = serde_repr.value();
+        let (name, args) = match value.trim().split_once('(') {
+            Some((name, args)) => (name.trim(), args.trim().strip_suffix(')')),
+            None => (value.trim(), Some("")),
+        };
+        let args = args
+            .map(|args| {
+                args.split(',')
+                    .map(str::trim)
+                    .filter(|arg| !arg.is_empty())
+                    .collect::<Vec<&str>>()
+            })
+            .unwrap_or_default();
+
+        match (name, args.as_slice()) {
+            ("external", []) => Ok(Tagging::External),
+            ("internal", [tag]) => Ok(Tagging::Internal {
+                tag: (*tag).to_string(),
+            }),
+            ("adjacent", [tag, content]) => Ok(Tagging::Adjacent {
+                tag: (*tag).to_string(),
+                content: (*content).to_string(),
+            }),
+            _ => Err(syn::Error::new_spanned(
+                serde_repr,
+                "Expected `#[evt(serde_repr = \"external\")]`, \
+                    `#[evt(serde_repr = \"internal(tag)\")]`, or \
+                    `#[evt(serde_repr = \"adjacent(tag, content)\")]`.",
+            )),
+        }
+    }
+}
+
+/// Returns the `#[serde(..)]` attributes in `attrs`.
+fn serde_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("serde"))
+}
+
+/// Returns the fields wrapped in `{ .. }` or `( .. )` to match `fields`.
+fn mirror_form(fields: &Fields, field_tokens: impl Iterator<Item = TokenStream>) -> TokenStream {
+    match fields {
+        Fields::Unit => TokenStream::new(),
+        Fields::Unnamed(_) => quote!((#(#field_tokens,)*)),
+        Fields::Named(_) => quote!({ #(#field_tokens,)* }),
+    }
+}
+
+/// Parses the value of a `serde` argument, such as `= "camelCase"` or
+/// `(serialize = "..")`, and returns its tokens.
+fn meta_value(nested_meta: &ParseNestedMeta) -> syn::Result<TokenStream> {
+    let input = nested_meta.input;
+    if input.peek(Token![=]) {
+        let eq_token: Token![=] = input.parse()?;
+        let value: Expr = input.parse()?;
+        Ok(quote!(#eq_token #value))
+    } else if input.peek(token::Paren) {
+        let group: Group = input.parse()?;
+        Ok(quote!(#group))
+    } else {
+        Ok(TokenStream::new())
+    }
+}
@@ -0,0 +1,41 @@
+use alloc::string::{String, ToString};
+use proc_macro2::{Ident, Span};
+
+/// Returns the `snake_case` form of an `UpperCamelCase` identifier.
+///
+/// Keywords are returned as raw identifiers, e.g. `Type` becomes `r#type`.
+pub(crate) fn snake_case_ident(ident: &Ident) -> Ident {
+    let snake_case = snake_case(&ident.to_string());
+    let span = Span::call_site();
+
+    match syn::parse_str::<Ident>(&snake_case) {
+        Ok(ident) => ident,
+        // `self`, `super`, `crate` cannot be raw identifiers.
+        Err(_) if matches!(snake_case.as_str(), "self" | "super" | "crate") => {
+            Ident::new(&alloc::format!("{snake_case}_"), span)
+        }
+        Err(_) => Ident::new_raw(&snake_case, span),
+    }
+}
+
+/// Converts an `UpperCamelCase` string to `snake_case`.
+///
+/// Acronyms are kept together, so `HTTPRequest` becomes `http_request`.
+pub(crate) fn snake_case(s: &str) -> String {
+    let s = s.strip_prefix("r#").unwrap_or(s);
+    let chars = s.chars().collect::<alloc::vec::Vec<char>>();
+    let mut snake_case = String::with_capacity(s.len() + 4);
+
+    chars.iter().enumerate().for_each(|(index, c)| {
+        if c.is_uppercase() && index > 0 {
+            let prev = chars[index - 1];
+            let next_is_lowercase = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+            if prev != '_' && (!prev.is_uppercase() || next_is_lowercase) {
+                snake_case.push('_');
+            }
+        }
+        snake_case.extend(c.to_lowercase());
+    });
+
+    snake_case
+}
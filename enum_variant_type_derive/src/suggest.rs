@@ -0,0 +1,67 @@
+use alloc::{format, string::String, vec::Vec};
+use quote::ToTokens;
+use syn::Path;
+
+/// Returns an error for an unknown `evt` parameter.
+///
+/// If a known parameter is spelt similarly, it is suggested in the message.
+///
+/// # Parameters
+///
+/// * `path`: Path of the unknown parameter, used for the error span.
+/// * `known_params`: Names of the parameters that are accepted.
+/// * `message`: Message to use when there is no similar parameter.
+pub(crate) fn unknown_param_error(path: &Path, known_params: &[&str], message: &str) -> syn::Error {
+    let param = path
+        .to_token_stream()
+        .into_iter()
+        .map(|token| format!("{token}"))
+        .collect::<String>();
+
+    match closest_match(&param, known_params) {
+        Some(known_param) => syn::Error::new_spanned(
+            path,
+            format!("Unknown `evt` parameter `{param}`, did you mean `{known_param}`?"),
+        ),
+        None => syn::Error::new_spanned(
+            path,
+            format!("Unknown `evt` parameter `{param}`. {message}"),
+        ),
+    }
+}
+
+/// Returns the candidate closest to `param`, if it is close enough to be a
+/// likely typo.
+fn closest_match<'a>(param: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let max_distance = core::cmp::max(param.chars().count(), 3) / 3;
+
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(param, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Returns the Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<char>>();
+    let mut distances = (0..=b.len()).collect::<Vec<usize>>();
+
+    a.chars().enumerate().for_each(|(i, a_char)| {
+        let mut diagonal = distances[0];
+        distances[0] = i + 1;
+
+        b.iter().enumerate().for_each(|(j, b_char)| {
+            let above = distances[j + 1];
+            distances[j + 1] = if a_char == *b_char {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(distances[j])
+            };
+            diagonal = above;
+        });
+    });
+
+    distances[b.len()]
+}
@@ -0,0 +1,115 @@
+use alloc::vec::Vec;
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::quote;
+use syn::{punctuated::Punctuated, Generics, Token, Variant, Visibility};
+
+use crate::{kind, variant_params::VariantParams};
+
+/// Returns the `#[evt(tag = ..)]` of each variant, or `None` if no variant has
+/// a tag.
+///
+/// Returns an error if only some variants have a tag, or if a tag is used by
+/// more than one variant.
+pub(crate) fn values(
+    variants: &Punctuated<Variant, Token![,]>,
+    variants_params: &[VariantParams],
+) -> syn::Result<Option<Vec<u16>>> {
+    if variants_params
+        .iter()
+        .all(|variant_params| variant_params.tag.is_none())
+    {
+        return Ok(None);
+    }
+
+    let mut tags = Vec::<u16>::with_capacity(variants.len());
+    for (variant, variant_params) in variants.iter().zip(variants_params) {
+        let variant_name = &variant.ident;
+        let tag_lit = variant_params.tag.as_ref().ok_or_else(|| {
+            syn::Error::new(
+                variant_name.span(),
+                alloc::format!(
+                    "`#[evt(tag = ..)]` is missing on `{variant_name}`. Every variant needs a \
+                        tag when any variant has one."
+                ),
+            )
+        })?;
+        let tag = tag_lit.base10_parse::<u16>()?;
+
+        if let Some(index) = tags.iter().position(|existing| *existing == tag) {
+            let existing_name = &variants[index].ident;
+            return Err(syn::Error::new(
+                tag_lit.span(),
+                alloc::format!(
+                    "Tag `{tag_lit}` of `{variant_name}` is already used by `{existing_name}`."
+                ),
+            ));
+        }
+        tags.push(tag);
+    }
+
+    Ok(Some(tags))
+}
+
+/// Returns the `TAG` constant for a variant's struct.
+pub(crate) fn struct_tokens(
+    variant_name: &Ident,
+    generics: &Generics,
+    tag: u16,
+    is_unit: bool,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let (impl_generics, ty_generics, where_clause) = if is_unit {
+        (None, None, None)
+    } else {
+        (Some(impl_generics), Some(ty_generics), where_clause)
+    };
+    let tag = Literal::u16_unsuffixed(tag);
+
+    quote! {
+        impl #impl_generics #variant_name #ty_generics #where_clause {
+            /// Tag of the variant this struct is generated from.
+            pub const TAG: u16 = #tag;
+        }
+    }
+}
+
+/// Returns `tag(&self)` and `kind_from_tag(tag)` for the enum.
+pub(crate) fn enum_tokens(
+    enum_name: &Ident,
+    vis: &Visibility,
+    generics: &Generics,
+    variants: &Punctuated<Variant, Token![,]>,
+    tags: &[u16],
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let kind_name = kind::kind_name(enum_name);
+    let variant_names = variants
+        .iter()
+        .map(|variant| &variant.ident)
+        .collect::<Vec<&Ident>>();
+    let tags = tags
+        .iter()
+        .copied()
+        .map(Literal::u16_unsuffixed)
+        .collect::<Vec<Literal>>();
+
+    quote! {
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            /// Returns the `#[evt(tag = ..)]` of this variant.
+            #vis const fn tag(&self) -> u16 {
+                match self {
+                    #(#enum_name::#variant_names { .. } => #tags,)*
+                }
+            }
+
+            /// Returns the kind of the variant with the given tag, or `None`
+            /// if no variant has the tag.
+            #vis const fn kind_from_tag(tag: u16) -> core::option::Option<#kind_name> {
+                match tag {
+                    #(#tags => core::option::Option::Some(#kind_name::#variant_names),)*
+                    _ => core::option::Option::None,
+                }
+            }
+        }
+    }
+}
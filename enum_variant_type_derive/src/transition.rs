@@ -0,0 +1,228 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    ext::IdentExt, punctuated::Punctuated, Generics, LitStr, Member, MetaList, Token, Variant,
+    Visibility,
+};
+
+use crate::generated_variant::GeneratedVariant;
+
+/// A transition declared by `#[evt(transition(to = Open, via = "open"))]`.
+#[derive(Debug)]
+pub(crate) struct Transition {
+    /// Name of the variant to transition to.
+    pub(crate) to: Ident,
+    /// Name of the method that performs the transition.
+    pub(crate) via: Ident,
+}
+
+impl Transition {
+    /// Parses the arguments of `transition(to = Open, via = "open")`.
+    pub(crate) fn parse(meta_list: &MetaList) -> syn::Result<Self> {
+        let mut to = None::<Ident>;
+        let mut via = None::<Ident>;
+
+        meta_list.parse_nested_meta(|transition_meta| {
+            if transition_meta.path.is_ident("to") {
+                to = Some(transition_meta.value()?.parse()?);
+                return Ok(());
+            }
+            if transition_meta.path.is_ident("via") {
+                let via_lit: LitStr = transition_meta.value()?.parse()?;
+                via = Some(via_lit.parse()?);
+                return Ok(());
+            }
+
+            Err(transition_meta.error(
+                "Expected variant `evt` attribute argument in the form: \
+                    `#[evt(transition(to = Variant, via = \"method_name\"))]`.",
+            ))
+        })?;
+
+        match (to, via) {
+            (Some(to), Some(via)) => Ok(Transition { to, via }),
+            _ => Err(syn::Error::new_spanned(
+                meta_list,
+                "Expected variant `evt` attribute argument in the form: \
+                    `#[evt(transition(to = Variant, via = \"method_name\"))]`.",
+            )),
+        }
+    }
+}
+
+/// Returns the name of the trait holding a variant's transitions, e.g.
+/// `ConnectingTransitions`.
+fn trait_name(variant_name: &Ident) -> Ident {
+    format_ident!("{}Transitions", variant_name.unraw())
+}
+
+/// Returns an intra-doc link to the variant's struct, e.g.
+/// ``[`Open`](states::Open)``, which resolves from the enum's module.
+fn struct_link(generated_variant: &GeneratedVariant) -> String {
+    let variant_name = &generated_variant.variant.ident;
+    let struct_path = generated_variant
+        .module_path
+        .iter()
+        .chain(core::iter::once(variant_name))
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()
+        .join("::");
+    alloc::format!("[`{variant_name}`]({struct_path})")
+}
+
+/// Returns the transition trait for each variant that declares transitions,
+/// and `MyEnum::transition(self, next)`.
+///
+/// Each transition method moves the fields that the source and target structs
+/// have in common by name, and takes the target's remaining fields as
+/// parameters.
+pub(crate) fn enum_tokens(
+    enum_name: &Ident,
+    vis: &Visibility,
+    generics: &Generics,
+    variants: &Punctuated<Variant, Token![,]>,
+    generated_variants: &[Option<GeneratedVariant>],
+) -> syn::Result<TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let generated_struct = |variant_name: &Ident| {
+        generated_variants
+            .iter()
+            .flatten()
+            .find(|generated_variant| generated_variant.variant.ident == *variant_name)
+            .filter(|generated_variant| generated_variant.impls.r#struct)
+    };
+    let struct_required_error = |variant_name: &Ident| {
+        syn::Error::new(
+            variant_name.span(),
+            alloc::format!(
+                "`#[evt(transition(..))]` requires a struct for `{variant_name}`, \
+                    but none is generated."
+            ),
+        )
+    };
+
+    let mut transition_traits = TokenStream::new();
+    let mut legal_transitions = Vec::<TokenStream>::new();
+    for source in generated_variants.iter().flatten() {
+        if source.transitions.is_empty() {
+            continue;
+        }
+
+        let source_name = &source.variant.ident;
+        if !source.impls.r#struct {
+            return Err(struct_required_error(source_name));
+        }
+        let source_ty = source.struct_ty(&ty_generics);
+        let trait_name = trait_name(source_name);
+        let source_link = struct_link(source);
+        let trait_doc =
+            alloc::format!("Transitions from {source_link} to other [`{enum_name}`] variants.");
+
+        let mut trait_fns = Vec::<TokenStream>::new();
+        let mut impl_fns = Vec::<TokenStream>::new();
+        for Transition { to, via } in &source.transitions {
+            if !variants.iter().any(|variant| variant.ident == *to) {
+                return Err(syn::Error::new(
+                    to.span(),
+                    alloc::format!("Unknown transition target `{to}` on `{enum_name}`."),
+                ));
+            }
+            let target = generated_struct(to).ok_or_else(|| struct_required_error(to))?;
+            let target_path = target.struct_path();
+            let target_ty = target.struct_ty(&ty_generics);
+
+            let source_members = source.fields.struct_members().collect::<Vec<Member>>();
+            let (params, inits) = target
+                .fields
+                .struct_fields()
+                .zip(target.fields.struct_members())
+                .enumerate()
+                .map(|(index, (field, member))| {
+                    let field_ty = &field.ty;
+                    match &member {
+                        Member::Named(field_name) if source_members.contains(&member) => {
+                            (None, quote!(#field_name: self.#field_name))
+                        }
+                        Member::Named(field_name) => {
+                            (Some(quote!(#field_name: #field_ty)), quote!(#field_name))
+                        }
+                        Member::Unnamed(_) => {
+                            let param = format_ident!("_{}", index);
+                            (Some(quote!(#param: #field_ty)), quote!(#member: #param))
+                        }
+                    }
+                })
+                .unzip::<_, _, Vec<Option<TokenStream>>, Vec<TokenStream>>();
+            let params = params.into_iter().flatten().collect::<Vec<TokenStream>>();
+            let target_link = struct_link(target);
+            let doc = alloc::format!(
+                "Transitions to {target_link}, moving the fields it shares with {source_link}."
+            );
+
+            trait_fns.push(quote! {
+                #[doc = #doc]
+                fn #via(self, #(#params),*) -> #target_ty;
+            });
+            impl_fns.push(quote! {
+                fn #via(self, #(#params),*) -> #target_ty {
+                    #target_path { #(#inits,)* }
+                }
+            });
+
+            let source_pattern = quote!(#enum_name::#source_name { .. });
+            let target_pattern = quote!(#enum_name::#to { .. });
+            legal_transitions.push(quote!((#source_pattern, #target_pattern)));
+        }
+
+        transition_traits.extend(quote! {
+            #[doc = #trait_doc]
+            #vis trait #trait_name #impl_generics #where_clause {
+                #(#trait_fns)*
+            }
+
+            impl #impl_generics #trait_name #ty_generics for #source_ty #where_clause {
+                #(#impl_fns)*
+            }
+        });
+    }
+
+    let illegal_transition = quote!(::enum_variant_type::IllegalTransition<Self>);
+    let transition_fn = (!legal_transitions.is_empty()).then(|| {
+        quote! {
+            impl #impl_generics #enum_name #ty_generics #where_clause {
+                /// Returns whether a `#[evt(transition(..))]` is declared
+                /// from this variant to `next`'s variant.
+                #vis fn can_transition(&self, next: &Self) -> bool {
+                    core::matches!((self, next), #(#legal_transitions)|*)
+                }
+
+                /// Returns `next` if a `#[evt(transition(..))]` is declared
+                /// from this variant to `next`'s variant, otherwise returns
+                /// both values in an error.
+                #vis fn transition(
+                    self,
+                    next: impl core::convert::Into<Self>,
+                ) -> core::result::Result<Self, #illegal_transition> {
+                    let next = next.into();
+                    if self.can_transition(&next) {
+                        core::result::Result::Ok(next)
+                    } else {
+                        core::result::Result::Err(::enum_variant_type::IllegalTransition {
+                            from: self,
+                            to: next,
+                        })
+                    }
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        #transition_traits
+        #transition_fn
+    })
+}
@@ -0,0 +1,166 @@
+use alloc::vec::Vec;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    visit::{self, Visit},
+    Generics, Path, Type,
+};
+
+use crate::{generated_variant::GeneratedVariant, variant_fields::VariantFields};
+
+/// Returns an error if two variants would generate overlapping
+/// `From<(A, B)> for MyEnum` impls.
+///
+/// Field types that mention one of the enum's type or const parameters may be
+/// any type, so they are treated as overlapping with every type in the same
+/// position. Other types are compared as written, so aliases of the same type
+/// are not caught.
+pub(crate) fn validate(
+    enum_name: &Ident,
+    generics: &Generics,
+    generated_variants: &[Option<GeneratedVariant>],
+) -> syn::Result<()> {
+    let params = generics
+        .type_params()
+        .map(|type_param| &type_param.ident)
+        .chain(
+            generics
+                .const_params()
+                .map(|const_param| &const_param.ident),
+        )
+        .collect::<Vec<&Ident>>();
+    let may_overlap = |ty_a: &Type, ty_b: &Type| {
+        ty_a == ty_b || mentions_any(ty_a, &params) || mentions_any(ty_b, &params)
+    };
+    let mut tuple_tys = Vec::<(Vec<&Type>, &Ident)>::new();
+    for generated_variant in generated_variants
+        .iter()
+        .flatten()
+        .filter(|generated_variant| generated_variant.impls.r#struct)
+        .filter(|generated_variant| generated_variant.impls.from)
+        .filter(|generated_variant| !generated_variant.fields.is_unit())
+    {
+        let variant_name = &generated_variant.variant.ident;
+        let field_tys = generated_variant
+            .fields
+            .struct_fields()
+            .map(|field| &field.ty)
+            .collect::<Vec<&Type>>();
+        if let Some((_, existing_name)) = tuple_tys.iter().find(|(tys, _)| {
+            tys.len() == field_tys.len()
+                && tys
+                    .iter()
+                    .zip(&field_tys)
+                    .all(|(ty_a, ty_b)| may_overlap(ty_a, ty_b))
+        }) {
+            return Err(syn::Error::new(
+                variant_name.span(),
+                alloc::format!(
+                    "`#[evt(tuple_conv)]` generates overlapping `From<(..)>` impls for \
+                        `{enum_name}` from both `{existing_name}` and `{variant_name}`, as \
+                        their fields may have the same types."
+                ),
+            ));
+        }
+        tuple_tys.push((field_tys, variant_name));
+    }
+
+    Ok(())
+}
+
+/// Returns whether the type mentions any of the generic parameters, e.g.
+/// `Vec<T>` mentions `T`, and `[u8; N]` mentions `N`.
+fn mentions_any(ty: &Type, params: &[&Ident]) -> bool {
+    struct MentionsAny<'a> {
+        params: &'a [&'a Ident],
+        mentions: bool,
+    }
+
+    impl<'ast> Visit<'ast> for MentionsAny<'_> {
+        fn visit_path(&mut self, path: &'ast Path) {
+            if path.leading_colon.is_none()
+                && path
+                    .segments
+                    .first()
+                    .is_some_and(|segment| self.params.contains(&&segment.ident))
+            {
+                self.mentions = true;
+            }
+            visit::visit_path(self, path);
+        }
+    }
+
+    let mut mentions_any = MentionsAny {
+        params,
+        mentions: false,
+    };
+    mentions_any.visit_type(ty);
+    mentions_any.mentions
+}
+
+/// Returns the conversions between a variant's struct and a tuple of its
+/// fields, in field order.
+///
+/// Nothing is generated for unit structs.
+///
+/// # Parameters
+///
+/// * `impl_from`: Whether to generate `From<(A, B)>` for the enum, which uses
+///   the `From` impl for the enum.
+pub(crate) fn struct_tokens(
+    enum_name: &Ident,
+    variant_name: &Ident,
+    generics: &Generics,
+    variant_fields: &VariantFields,
+    impl_from: bool,
+) -> TokenStream {
+    if variant_fields.is_unit() {
+        return TokenStream::new();
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let tuple_ty = tuple_ty(variant_fields);
+    let members = variant_fields.struct_members().collect::<Vec<_>>();
+    let bindings = (0..members.len())
+        .map(|index| format_ident!("_{}", index))
+        .collect::<Vec<Ident>>();
+    let impl_from_tuple_for_enum = impl_from.then(|| {
+        quote! {
+            impl #impl_generics core::convert::From<#tuple_ty> for #enum_name #ty_generics
+            #where_clause {
+                fn from(tuple: #tuple_ty) -> Self {
+                    <Self as core::convert::From<#variant_name #ty_generics>>::from(
+                        <#variant_name #ty_generics as core::convert::From<#tuple_ty>>::from(tuple),
+                    )
+                }
+            }
+        }
+    });
+
+    quote! {
+        impl #impl_generics core::convert::From<#tuple_ty> for #variant_name #ty_generics
+        #where_clause {
+            fn from((#(#bindings,)*): #tuple_ty) -> Self {
+                Self { #(#members: #bindings,)* }
+            }
+        }
+
+        impl #impl_generics core::convert::From<#variant_name #ty_generics> for #tuple_ty
+        #where_clause {
+            fn from(variant_struct: #variant_name #ty_generics) -> Self {
+                (#(variant_struct.#members,)*)
+            }
+        }
+
+        #impl_from_tuple_for_enum
+    }
+}
+
+/// Returns the tuple of the struct's field types, such as `(u32, u64,)`.
+fn tuple_ty(variant_fields: &VariantFields) -> TokenStream {
+    let field_tys = variant_fields
+        .struct_fields()
+        .map(|field| &field.ty)
+        .collect::<Vec<&Type>>();
+    quote!((#(#field_tys,)*))
+}
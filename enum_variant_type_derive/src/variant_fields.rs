@@ -0,0 +1,258 @@
+use alloc::{format, string::String, vec::Vec};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{parse_quote, Expr, Field, Fields, Member, Path, Visibility};
+
+use crate::field_params::FieldParams;
+
+/// Fields of a variant, and how they map to the generated struct's fields.
+#[derive(Debug)]
+pub(crate) struct VariantFields {
+    /// Whether the variant is a unit, tuple, or named variant.
+    kind: FieldsKind,
+    /// Each field in the variant.
+    fields: Vec<VariantField>,
+}
+
+/// Whether a variant is a unit, tuple, or named variant.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum FieldsKind {
+    Unit,
+    Unnamed,
+    Named,
+}
+
+/// A field of a variant, and the field it maps to in the generated struct.
+#[derive(Debug)]
+struct VariantField {
+    /// The field in the generated struct.
+    struct_field: Field,
+    /// Name or index of the field in the variant.
+    enum_member: Member,
+    /// Variable the field's value is bound to during conversion.
+    binding: Ident,
+    /// Whether the field is left out of the generated struct.
+    skip: bool,
+    /// Value of a skipped field when converting the struct into the enum.
+    default: Option<Expr>,
+}
+
+impl VariantFields {
+    /// Returns the fields for the generated struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `fields`: Fields of the variant.
+    /// * `vis`: Visibility to give to each struct field.
+    /// * `ns`: The `evt` namespace.
+    pub(crate) fn new(fields: &Fields, vis: &Visibility, ns: &Path) -> syn::Result<Self> {
+        let kind = match fields {
+            Fields::Unit => FieldsKind::Unit,
+            Fields::Unnamed(..) => FieldsKind::Unnamed,
+            Fields::Named(..) => FieldsKind::Named,
+        };
+        let fields = fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let field_params = FieldParams::parse(field, ns)?;
+                let (enum_member, binding) = match &field.ident {
+                    Some(ident) => (Member::Named(ident.clone()), ident.clone()),
+                    None => (
+                        Member::Unnamed(index.into()),
+                        Ident::new(&format!("_{index}"), Span::call_site()),
+                    ),
+                };
+
+                // Need to attach visibility modifier to fields.
+                let mut struct_field = field.clone();
+                struct_field.vis = vis.clone();
+                struct_field
+                    .attrs
+                    .retain(|attr| field_params.copies_attr(attr.path(), ns));
+                struct_field.attrs.extend(
+                    field_params
+                        .attrs
+                        .iter()
+                        .map(|meta| -> syn::Attribute { parse_quote!(#[#meta]) }),
+                );
+                if let Some(rename) = field_params.rename {
+                    struct_field.ident = Some(rename);
+                }
+
+                Ok(VariantField {
+                    struct_field,
+                    enum_member,
+                    binding,
+                    skip: field_params.skip,
+                    default: field_params.default,
+                })
+            })
+            .collect::<syn::Result<Vec<VariantField>>>()?;
+
+        Ok(VariantFields { kind, fields })
+    }
+
+    /// Returns whether the variant is a unit variant.
+    pub(crate) fn is_unit(&self) -> bool {
+        self.kind == FieldsKind::Unit
+    }
+
+    /// Returns the fields to declare in the generated struct.
+    pub(crate) fn struct_fields(&self) -> impl Iterator<Item = &Field> {
+        self.fields
+            .iter()
+            .filter(|field| !field.skip)
+            .map(|field| &field.struct_field)
+    }
+
+    /// Returns the variant's fields that are kept in the generated struct, as
+    /// declared in the enum, with the variable each is bound to by
+    /// [`Self::struct_form`].
+    ///
+    /// # Parameters
+    ///
+    /// * `fields`: Fields of the variant, as passed to [`Self::new`].
+    pub(crate) fn kept_enum_fields<'f>(
+        &'f self,
+        fields: &'f Fields,
+    ) -> impl Iterator<Item = (&'f Field, &'f Ident)> {
+        fields
+            .iter()
+            .zip(self.fields.iter())
+            .filter(|(_, field)| !field.skip)
+            .map(|(enum_field, field)| (enum_field, &field.binding))
+    }
+
+    /// Removes attributes with the given name from the generated struct's
+    /// fields.
+    pub(crate) fn strip_struct_field_attrs(&mut self, attr_name: &str) {
+        self.fields.iter_mut().for_each(|field| {
+            field
+                .struct_field
+                .attrs
+                .retain(|attr| !attr.path().is_ident(attr_name))
+        });
+    }
+
+    /// Returns whether the variant is a named variant.
+    pub(crate) fn is_named(&self) -> bool {
+        self.kind == FieldsKind::Named
+    }
+
+    /// Returns whether any field is left out of the generated struct.
+    pub(crate) fn has_skipped_fields(&self) -> bool {
+        self.fields.iter().any(|field| field.skip)
+    }
+
+    /// Returns the name or index of each field in the generated struct.
+    pub(crate) fn struct_members(&self) -> impl Iterator<Item = Member> + '_ {
+        self.struct_fields()
+            .enumerate()
+            .map(|(index, field)| match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(index.into()),
+            })
+    }
+
+    /// Returns the tokens to construct or deconstruct the generated struct,
+    /// such as `{ a, renamed: b, }` or `(_0, _1,)`.
+    pub(crate) fn struct_form(&self) -> TokenStream {
+        self.form(|field| {
+            let binding = &field.binding;
+            match &field.struct_field.ident {
+                _ if field.skip => TokenStream::new(),
+                Some(ident) if *ident != *binding => quote!(#ident: #binding,),
+                _ => quote!(#binding,),
+            }
+        })
+    }
+
+    /// Returns the tokens to deconstruct the enum variant, such as `{ a, b, }`
+    /// or `(_0, _,)`.
+    ///
+    /// Skipped fields are matched with `_`.
+    pub(crate) fn enum_pattern(&self) -> TokenStream {
+        self.enum_form(|_| quote!(_))
+    }
+
+    /// Returns the tokens to construct the enum variant, such as `{ a, b, }` or
+    /// `(_0, Default::default(),)`.
+    ///
+    /// Skipped fields are set to their default value.
+    pub(crate) fn enum_construction(&self) -> TokenStream {
+        self.enum_form(|field| match &field.default {
+            Some(default) => quote!(#default),
+            None => quote!(core::default::Default::default()),
+        })
+    }
+
+    /// Returns the lines of documentation describing the skipped fields.
+    ///
+    /// This is empty if no fields are skipped.
+    pub(crate) fn skipped_fields_doc(
+        &self,
+        enum_name: &Ident,
+        variant_name: &Ident,
+    ) -> Vec<String> {
+        let skipped_fields = self
+            .fields
+            .iter()
+            .filter(|field| field.skip)
+            .map(|field| {
+                let member = match &field.enum_member {
+                    Member::Named(ident) => format!("{ident}"),
+                    Member::Unnamed(index) => format!("{}", index.index),
+                };
+                let default = match &field.default {
+                    Some(default) => format!("{}", quote!(#default)),
+                    None => String::from("Default::default()"),
+                };
+
+                format!("* `{member}`: Set to `{default}` when converted back.")
+            })
+            .collect::<Vec<String>>();
+
+        if skipped_fields.is_empty() {
+            skipped_fields
+        } else {
+            let mut doc = alloc::vec![
+                String::new(),
+                format!(
+                    "Conversion from `{enum_name}::{variant_name}` is lossy, \
+                        the following fields are not stored:"
+                ),
+                String::new(),
+            ];
+            doc.extend(skipped_fields);
+            doc
+        }
+    }
+
+    fn enum_form(
+        &self,
+        skipped_field_tokens: impl Fn(&VariantField) -> TokenStream,
+    ) -> TokenStream {
+        self.form(|field| {
+            let binding = &field.binding;
+            let value = if field.skip {
+                skipped_field_tokens(field)
+            } else {
+                quote!(#binding)
+            };
+            match &field.enum_member {
+                Member::Named(ident) if field.skip || *ident != *binding => quote!(#ident: #value,),
+                _ => quote!(#value,),
+            }
+        })
+    }
+
+    fn form(&self, field_tokens: impl Fn(&VariantField) -> TokenStream) -> TokenStream {
+        let fields_tokens = self.fields.iter().map(field_tokens);
+        match self.kind {
+            FieldsKind::Unit => TokenStream::new(),
+            FieldsKind::Unnamed => quote!((#(#fields_tokens)*)),
+            FieldsKind::Named => quote!({ #(#fields_tokens)* }),
+        }
+    }
+}
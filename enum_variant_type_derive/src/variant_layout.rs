@@ -0,0 +1,173 @@
+use alloc::vec::Vec;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{DataEnum, Generics, Visibility};
+
+use crate::{repr::Repr, variant_fields::VariantFields};
+
+/// Returns an error if `#[evt(as_variant_ref)]` cannot be used on the enum.
+///
+/// The enum must be `repr(C)` or `repr(C, Int)`, so that its layout is
+/// defined, and cannot be generic, so that the layout can be checked at
+/// compile time.
+pub(crate) fn validate(
+    enum_name: &Ident,
+    enum_repr: &Repr,
+    generics: &Generics,
+) -> syn::Result<()> {
+    if !enum_repr.is_c_only() {
+        return Err(syn::Error::new(
+            enum_name.span(),
+            "`#[evt(as_variant_ref)]` requires the enum to be `#[repr(C)]` or `#[repr(C, Int)]`.",
+        ));
+    }
+    if !generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            generics,
+            "`#[evt(as_variant_ref)]` cannot be used on generic enums.",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Returns the payload offset constant, and the `as_variant_ref` and
+/// `as_variant_mut` methods for the enum.
+///
+/// The offset is computed from a mirror of the enum's layout, as specified for
+/// `repr(C)` enums: a `repr(C)` struct of the tag followed by a `repr(C)` union
+/// of each variant's fields.
+pub(crate) fn enum_tokens(
+    enum_name: &Ident,
+    vis: &Visibility,
+    enum_repr: &Repr,
+    data_enum: &DataEnum,
+) -> TokenStream {
+    let tag_repr = match &enum_repr.int {
+        Some(int) => quote!(#[repr(#int)]),
+        None => quote!(#[repr(C)]),
+    };
+    let tag_variants = data_enum.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        match &variant.discriminant {
+            Some((eq, discriminant)) => quote!(#variant_name #eq #discriminant),
+            None => quote!(#variant_name),
+        }
+    });
+    let (payload_fields, payload_structs) = data_enum
+        .variants
+        .iter()
+        .enumerate()
+        .map(|(index, variant)| {
+            let payload_field = format_ident!("_{index}");
+            let payload_struct = format_ident!("__EvtVariant{index}");
+            let field_tys = variant.fields.iter().map(|field| &field.ty);
+
+            (
+                quote!(#payload_field: core::mem::ManuallyDrop<#payload_struct>),
+                quote!(#[repr(C)] struct #payload_struct(#(#field_tys,)*);),
+            )
+        })
+        .unzip::<_, _, Vec<TokenStream>, Vec<TokenStream>>();
+
+    quote! {
+        impl #enum_name {
+            /// Offset of the variant fields from the start of the enum.
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            const __EVT_PAYLOAD_OFFSET: usize = {
+                #tag_repr
+                enum __EvtTag { #(#tag_variants,)* }
+                #(#payload_structs)*
+                #[repr(C)]
+                union __EvtPayload { #(#payload_fields,)* }
+                #[repr(C)]
+                struct __EvtRepr {
+                    tag: __EvtTag,
+                    payload: __EvtPayload,
+                }
+
+                assert!(
+                    core::mem::size_of::<#enum_name>() == core::mem::size_of::<__EvtRepr>(),
+                    "Enum size does not match its `repr(C)` layout.",
+                );
+                assert!(
+                    core::mem::align_of::<#enum_name>() == core::mem::align_of::<__EvtRepr>(),
+                    "Enum alignment does not match its `repr(C)` layout.",
+                );
+
+                core::mem::offset_of!(__EvtRepr, payload)
+            };
+
+            /// Returns a reference to the variant's fields as the generated
+            /// struct, or `None` if the enum is a different variant.
+            #vis fn as_variant_ref<V>(&self) -> core::option::Option<&V>
+            where
+                V: ::enum_variant_type::VariantLayout<Self>,
+            {
+                ::enum_variant_type::variant_ref(self)
+            }
+
+            /// Returns a mutable reference to the variant's fields as the
+            /// generated struct, or `None` if the enum is a different variant.
+            #vis fn as_variant_mut<V>(&mut self) -> core::option::Option<&mut V>
+            where
+                V: ::enum_variant_type::VariantLayout<Self>,
+            {
+                ::enum_variant_type::variant_mut(self)
+            }
+        }
+
+        const _: usize = #enum_name::__EVT_PAYLOAD_OFFSET;
+    }
+}
+
+/// Returns the layout assertions and `VariantLayout` impl for a variant's
+/// struct.
+///
+/// Nothing is generated if the struct's layout may differ from the variant's
+/// fields, i.e. when fields are skipped or the struct is not just `repr(C)`.
+pub(crate) fn variant_tokens(
+    enum_name: &Ident,
+    variant_name: &Ident,
+    variant_fields: &VariantFields,
+    struct_repr: &Repr,
+) -> TokenStream {
+    if variant_fields.has_skipped_fields() || !struct_repr.is_c_only() {
+        return TokenStream::new();
+    }
+
+    let field_tys = variant_fields.struct_fields().map(|field| &field.ty);
+    let offset_asserts = variant_fields
+        .struct_members()
+        .enumerate()
+        .map(|(index, member)| {
+            let index = syn::Index::from(index);
+            quote! {
+                assert!(
+                    core::mem::offset_of!(#variant_name, #member)
+                        == core::mem::offset_of!(__EvtFields, #index)
+                );
+            }
+        });
+
+    quote! {
+        const _: () = {
+            #[allow(dead_code)]
+            #[repr(C)]
+            struct __EvtFields(#(#field_tys,)*);
+
+            assert!(core::mem::size_of::<#variant_name>() == core::mem::size_of::<__EvtFields>());
+            assert!(core::mem::align_of::<#variant_name>() == core::mem::align_of::<__EvtFields>());
+            #(#offset_asserts)*
+        };
+
+        unsafe impl ::enum_variant_type::VariantLayout<#enum_name> for #variant_name {
+            const PAYLOAD_OFFSET: usize = #enum_name::__EVT_PAYLOAD_OFFSET;
+
+            fn is_variant(enum_variant: &#enum_name) -> bool {
+                core::matches!(enum_variant, #enum_name::#variant_name { .. })
+            }
+        }
+    }
+}
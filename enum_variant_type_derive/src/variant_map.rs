@@ -0,0 +1,123 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, Generics, Visibility};
+
+use crate::kind;
+
+/// Returns the `MyEnumVariantMap<V>` array backed map, with one value per
+/// `MyEnumKind`.
+///
+/// The value parameter is named `__EvtValue`, so it does not clash with the
+/// enum's generics in `get_for` and `get_for_mut`.
+pub(crate) fn enum_tokens(enum_name: &Ident, vis: &Visibility, generics: &Generics) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let kind_name = kind::kind_name(enum_name);
+    let map_name = format_ident!("{}VariantMap", enum_name.unraw());
+    let doc =
+        alloc::format!("Map with one value per [`{enum_name}`] variant kind, stored in an array.");
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        #vis struct #map_name<__EvtValue> {
+            values: [__EvtValue; #kind_name::COUNT],
+        }
+
+        impl<__EvtValue> #map_name<__EvtValue> {
+            /// Returns a map with the given values, in declaration order.
+            #vis const fn from_array(values: [__EvtValue; #kind_name::COUNT]) -> Self {
+                Self { values }
+            }
+
+            /// Returns a map with the value for each kind returned by the
+            /// closure.
+            #vis fn from_fn(mut f: impl core::ops::FnMut(#kind_name) -> __EvtValue) -> Self {
+                Self {
+                    values: core::array::from_fn(|index| f(#kind_name::ALL[index])),
+                }
+            }
+
+            /// Returns the value for the variant struct `T`.
+            #vis fn get<T>(&self) -> &__EvtValue
+            where
+                T: ::enum_variant_type::VariantKind<Kind = #kind_name>,
+            {
+                &self.values[T::KIND as usize]
+            }
+
+            /// Returns the value for the variant struct `T` mutably.
+            #vis fn get_mut<T>(&mut self) -> &mut __EvtValue
+            where
+                T: ::enum_variant_type::VariantKind<Kind = #kind_name>,
+            {
+                &mut self.values[T::KIND as usize]
+            }
+
+            /// Returns the value for the given variant's kind.
+            #vis fn get_for #impl_generics (&self, enum_variant: &#enum_name #ty_generics) -> &__EvtValue
+            #where_clause
+            {
+                &self[enum_variant.kind()]
+            }
+
+            /// Returns the value for the given variant's kind mutably.
+            #vis fn get_for_mut #impl_generics (
+                &mut self,
+                enum_variant: &#enum_name #ty_generics,
+            ) -> &mut __EvtValue
+            #where_clause
+            {
+                &mut self[enum_variant.kind()]
+            }
+
+            /// Returns an iterator over each kind and its value, in declaration
+            /// order.
+            #vis fn iter(&self) -> impl core::iter::Iterator<Item = (#kind_name, &__EvtValue)> {
+                #kind_name::ALL.into_iter().zip(self.values.iter())
+            }
+
+            /// Returns an iterator over each kind and its value mutably, in
+            /// declaration order.
+            #vis fn iter_mut(&mut self) -> impl core::iter::Iterator<Item = (#kind_name, &mut __EvtValue)> {
+                #kind_name::ALL.into_iter().zip(self.values.iter_mut())
+            }
+
+            /// Returns the values, in declaration order.
+            #vis fn into_array(self) -> [__EvtValue; #kind_name::COUNT] {
+                self.values
+            }
+        }
+
+        impl<__EvtValue> core::default::Default for #map_name<__EvtValue>
+        where
+            __EvtValue: core::default::Default,
+        {
+            fn default() -> Self {
+                Self::from_fn(|_| __EvtValue::default())
+            }
+        }
+
+        impl<__EvtValue> core::ops::Index<#kind_name> for #map_name<__EvtValue> {
+            type Output = __EvtValue;
+
+            fn index(&self, kind: #kind_name) -> &__EvtValue {
+                &self.values[kind as usize]
+            }
+        }
+
+        impl<__EvtValue> core::ops::IndexMut<#kind_name> for #map_name<__EvtValue> {
+            fn index_mut(&mut self, kind: #kind_name) -> &mut __EvtValue {
+                &mut self.values[kind as usize]
+            }
+        }
+
+        impl<__EvtValue> core::fmt::Debug for #map_name<__EvtValue>
+        where
+            __EvtValue: core::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_map().entries(self.iter()).finish()
+            }
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use alloc::vec::Vec;
+use proc_macro2::Ident;
+use proc_macro_roids::namespace_parameters;
+use quote::ToTokens;
+use syn::{punctuated::Punctuated, Attribute, Expr, ExprLit, Lit, LitInt, Meta, Path, Token, Type};
+
+use crate::{
+    impls::Impls, module_params::module_path_segments, repr::Repr, suggest, transition::Transition,
+};
+
+/// Parameters accepted by an `evt` attribute on a variant.
+///
+/// When `#[evt(strict)]` is specified on the enum, any other parameter must be
+/// wrapped in `attr(..)` to be attached to the generated struct. Without it,
+/// other parameters are attached as attributes, as they were before `strict`
+/// existed.
+const VARIANT_PARAMS: &[&str] = &[
+    "skip",
+    "include",
+    "impls",
+    "module",
+    "no_attr",
+    "attr",
+    "derive",
+    "repr",
+    "transition",
+    "response",
+    "tag",
+];
+
+/// Parameters specified by `#[evt(..)]` on a variant.
+#[derive(Debug, Default)]
+pub(crate) struct VariantParams {
+    /// Path of the module to place the struct in, relative to the enum's
+    /// module.
+    pub(crate) module: Vec<Ident>,
+    /// Attributes to attach to the generated struct.
+    pub(crate) struct_attrs: Vec<Meta>,
+    /// Enum level `attr(..)` attributes to not attach to the generated struct.
+    ///
+    /// `Some` with no paths means none of the enum level attributes are
+    /// attached.
+    pub(crate) no_attr: Option<Vec<Path>>,
+    /// Items to generate, overriding the enum level `impls(..)`.
+    pub(crate) impls: Option<Impls>,
+    /// Items not to generate, from `skip` or `skip(..)`.
+    pub(crate) skip: Option<Impls>,
+    /// `repr` of the generated struct, overriding the enum's `repr`.
+    pub(crate) repr: Option<Repr>,
+    /// Transitions from the generated struct to other variants' structs.
+    pub(crate) transitions: Vec<Transition>,
+    /// Type of the response to the variant, when used as a request.
+    pub(crate) response: Option<Type>,
+    /// Stable numeric tag of the variant.
+    pub(crate) tag: Option<LitInt>,
+    /// Whether the variant has any `evt` parameters, which includes it when
+    /// `#[evt(opt_in)]` is specified on the enum.
+    pub(crate) tagged: bool,
+}
+
+impl VariantParams {
+    /// Parses the `evt` attributes on a variant.
+    ///
+    /// # Parameters
+    ///
+    /// * `attrs`: Attributes on the variant.
+    /// * `ns`: The `evt` namespace.
+    /// * `strict`: Whether to reject parameters that are not known.
+    pub(crate) fn parse(attrs: &[Attribute], ns: &Path, strict: bool) -> syn::Result<Self> {
+        let mut variant_params = VariantParams::default();
+
+        for meta in namespace_parameters(attrs, ns) {
+            variant_params.tagged = true;
+
+            // `#[evt(module = "some_module_name")]`
+            if meta.path().is_ident("module") {
+                let module_name = match &meta {
+                    Meta::NameValue(name_value) => match &name_value.value {
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(module_name),
+                            ..
+                        }) => Some(module_name),
+                        _ => None,
+                    },
+                    _ => None,
+                }
+                .ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        &meta,
+                        "Expected variant `evt` attribute argument in the form: \
+                            `#[evt(module = \"some_module_name\")]`.",
+                    )
+                })?;
+
+                variant_params.module = module_path_segments(module_name)?;
+            } else if meta.path().is_ident("no_attr") {
+                // `#[evt(no_attr)]`, `#[evt(no_attr(serde, must_use))]`
+                let no_attr = variant_params.no_attr.get_or_insert_with(Vec::new);
+                match &meta {
+                    Meta::Path(_) => {}
+                    Meta::List(list) => list.parse_nested_meta(|nested_meta| {
+                        no_attr.push(nested_meta.path);
+                        Ok(())
+                    })?,
+                    Meta::NameValue(_) => {
+                        return Err(syn::Error::new_spanned(
+                            &meta,
+                            "Expected variant `evt` attribute argument in the form: \
+                                `#[evt(no_attr)]` or `#[evt(no_attr(attr_name))]`.",
+                        ));
+                    }
+                }
+            } else if meta.path().is_ident("attr") {
+                // `#[evt(attr(serde(rename_all = "camelCase"), must_use))]`
+                let list = meta.require_list()?;
+                variant_params
+                    .struct_attrs
+                    .extend(list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?);
+            } else if meta.path().is_ident("skip") {
+                // `#[evt(skip)]`, `#[evt(skip(try_from))]`
+                let skip = match &meta {
+                    Meta::Path(_) => Impls::ALL,
+                    Meta::List(meta_list) => Impls::parse_meta_list(meta_list)?,
+                    Meta::NameValue(_) => {
+                        return Err(syn::Error::new_spanned(
+                            &meta,
+                            "Expected variant `evt` attribute argument in the form: \
+                                `#[evt(skip)]` or `#[evt(skip(try_from))]`.",
+                        ));
+                    }
+                };
+                variant_params.skip = Some(skip);
+            } else if meta.path().is_ident("repr") {
+                // `#[evt(repr(transparent))]`
+                variant_params
+                    .repr
+                    .get_or_insert_with(Repr::default)
+                    .parse_meta_list(meta.require_list()?)?;
+            } else if meta.path().is_ident("transition") {
+                // `#[evt(transition(to = Open, via = "open"))]`
+                variant_params
+                    .transitions
+                    .push(Transition::parse(meta.require_list()?)?);
+            } else if meta.path().is_ident("response") {
+                // `#[evt(response = GetUserResp)]`
+                let name_value = meta.require_name_value()?;
+                variant_params.response = Some(syn::parse2(name_value.value.to_token_stream())?);
+            } else if meta.path().is_ident("tag") {
+                // `#[evt(tag = 0x12)]`
+                let name_value = meta.require_name_value()?;
+                variant_params.tag = Some(syn::parse2(name_value.value.to_token_stream())?);
+            } else if meta.path().is_ident("include") {
+                // `#[evt(include)]`
+                meta.require_path_only()?;
+            } else if meta.path().is_ident("impls") {
+                // `#[evt(impls(struct, from))]`
+                variant_params.impls = Some(Impls::parse_meta_list(meta.require_list()?)?);
+            } else if meta.path().is_ident("derive") || !strict {
+                variant_params.struct_attrs.push(meta);
+            } else {
+                return Err(suggest::unknown_param_error(
+                    meta.path(),
+                    VARIANT_PARAMS,
+                    "Attributes for the generated struct must be wrapped in `attr(..)` \
+                        when `#[evt(strict)]` is used.",
+                ));
+            }
+        }
+
+        Ok(variant_params)
+    }
+
+    /// Returns the items to generate for the variant.
+    ///
+    /// # Parameters
+    ///
+    /// * `enum_impls`: Items to generate as specified on the enum.
+    /// * `opt_in`: Whether only variants with `evt` parameters are generated.
+    pub(crate) fn impls(&self, enum_impls: Impls, opt_in: bool) -> Impls {
+        if opt_in && !self.tagged {
+            return Impls::NONE;
+        }
+
+        let impls = self.impls.unwrap_or(enum_impls);
+        match self.skip {
+            Some(skip) => impls.without(skip),
+            None => impls,
+        }
+    }
+
+    /// Returns whether an enum level `attr(..)` attribute should be attached
+    /// to the generated struct.
+    pub(crate) fn includes_enum_attr(&self, attr: &Meta) -> bool {
+        match &self.no_attr {
+            None => true,
+            Some(no_attr) if no_attr.is_empty() => false,
+            Some(no_attr) => !no_attr.iter().any(|path| attr.path() == path),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{parse_quote, Field, Generics, Visibility};
+
+use crate::variant_fields::VariantFields;
+
+/// Whether a view borrows the variant's fields immutably or mutably.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Borrow {
+    Ref,
+    Mut,
+}
+
+impl Borrow {
+    /// Returns the name of the view struct for a variant, e.g. `StructRef`.
+    pub(crate) fn view_name(self, variant_name: &Ident) -> Ident {
+        match self {
+            Borrow::Ref => format_ident!("{}Ref", variant_name),
+            Borrow::Mut => format_ident!("{}Mut", variant_name),
+        }
+    }
+}
+
+/// Returns the generics of a view struct, which borrows for `'evt`.
+pub(crate) fn view_generics(generics: &Generics) -> Generics {
+    let mut view_generics = generics.clone();
+    view_generics.params.insert(0, parse_quote!('evt));
+    view_generics
+}
+
+/// Returns the `StructRef` and `StructMut` views of a variant's fields, their
+/// `TryFrom<&Enum>` and `TryFrom<&mut Enum>` impls, and the `VariantView`
+/// impls that link the struct to them.
+///
+/// Unit variants have no views, as the unit struct is used by value.
+pub(crate) fn struct_tokens(
+    enum_name: &Ident,
+    variant_name: &Ident,
+    vis: &Visibility,
+    generics: &Generics,
+    variant_fields: &VariantFields,
+) -> TokenStream {
+    let view_generics = view_generics(generics);
+    let (view_impl_generics, view_ty_generics, where_clause) = view_generics.split_for_impl();
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let enum_pattern = variant_fields.enum_pattern();
+    let struct_form = variant_fields.struct_form();
+    let struct_ty = if variant_fields.is_unit() {
+        quote!(#variant_name)
+    } else {
+        quote!(#variant_name #ty_generics)
+    };
+    let try_from_borrow = |borrow: Borrow, view: &Ident, view_ty: TokenStream| {
+        let enum_ref = match borrow {
+            Borrow::Ref => quote!(&'evt #enum_name #ty_generics),
+            Borrow::Mut => quote!(&'evt mut #enum_name #ty_generics),
+        };
+        quote! {
+            impl #view_impl_generics ::enum_variant_type::VariantView<#enum_ref>
+                for #struct_ty
+            #where_clause {
+                type View = #view_ty;
+            }
+
+            impl #view_impl_generics core::convert::TryFrom<#enum_ref> for #view_ty #where_clause {
+                type Error = #enum_ref;
+
+                fn try_from(enum_variant: #enum_ref) -> Result<Self, Self::Error> {
+                    match enum_variant {
+                        #enum_name::#variant_name #enum_pattern => {
+                            core::result::Result::Ok(#view #struct_form)
+                        }
+                        enum_variant => core::result::Result::Err(enum_variant),
+                    }
+                }
+            }
+        }
+    };
+
+    if variant_fields.is_unit() {
+        return [Borrow::Ref, Borrow::Mut]
+            .into_iter()
+            .map(|borrow| try_from_borrow(borrow, variant_name, quote!(#variant_name)))
+            .collect();
+    }
+
+    [Borrow::Ref, Borrow::Mut]
+        .into_iter()
+        .map(|borrow| {
+            let view_name = borrow.view_name(variant_name);
+            let view_fields = variant_fields.struct_fields().map(|field| {
+                let ty = &field.ty;
+                let mut view_field: Field = field.clone();
+                view_field.attrs.retain(|attr| attr.path().is_ident("doc"));
+                view_field.ty = match borrow {
+                    Borrow::Ref => parse_quote!(&'evt #ty),
+                    Borrow::Mut => parse_quote!(&'evt mut #ty),
+                };
+                view_field
+            });
+            let doc = match borrow {
+                Borrow::Ref => alloc::format!("Borrowed fields of [`{variant_name}`]."),
+                Borrow::Mut => alloc::format!("Mutably borrowed fields of [`{variant_name}`]."),
+            };
+
+            let view_struct = if variant_fields.is_named() {
+                quote! {
+                    #[doc = #doc]
+                    #vis struct #view_name #view_ty_generics #where_clause {
+                        #(#view_fields,)*
+                    }
+                }
+            } else {
+                quote! {
+                    #[doc = #doc]
+                    #vis struct #view_name #view_ty_generics (#(#view_fields,)*) #where_clause;
+                }
+            };
+            let try_from_enum =
+                try_from_borrow(borrow, &view_name, quote!(#view_name #view_ty_generics));
+
+            quote! {
+                #view_struct
+                #try_from_enum
+            }
+        })
+        .collect()
+}
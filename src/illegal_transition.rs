@@ -0,0 +1,21 @@
+use core::fmt;
+
+/// Error returned by `MyEnum::transition` when no `#[evt(transition(..))]` is
+/// declared from one variant to the other.
+///
+/// Both values are returned so that neither is lost.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IllegalTransition<E> {
+    /// The value that was being transitioned from.
+    pub from: E,
+    /// The value that was being transitioned to.
+    pub to: E,
+}
+
+impl<E> fmt::Display for IllegalTransition<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("no transition is declared between these variants")
+    }
+}
+
+impl<E> core::error::Error for IllegalTransition<E> where E: fmt::Debug {}
@@ -1,6 +1,5 @@
 #![deny(missing_debug_implementations, missing_docs)]
 #![no_std]
-#![recursion_limit = "128"]
 
 //! Proc macro derive to generate structs from enum variants.
 //!
@@ -150,628 +149,199 @@
 //! * `#[evt(derive(Clone, Copy))]`: Derives `Clone`, `Copy` on **every**
 //!   variant.
 //! * `#[evt(module = "module1")]`: Generated structs are placed into `mod
-//!   module1 { ... }`.
+//!   module1 { ... }`. Nested paths such as `"a::b"` and raw identifiers such
+//!   as `"r#type"` are supported.
+//! * `#[evt(module)]`: Generated structs are placed into a module named after
+//!   the enum in `snake_case`.
+//! * `#[evt(module(name = "a::b", vis = "pub(crate)", attrs(..), imports(..),
+//!   reexport))]`: Generated structs are placed into a module with the given
+//!   options, all of which are optional:
+//!
+//!     - `vis`: Visibility of the module, defaults to the enum's visibility.
+//!     - `attrs(doc = "..", allow(..))`: Attributes to attach to the module.
+//!     - `imports(crate::Type, crate::module::*)`: Items to `use` in the module
+//!       instead of `use super::*`. The enum itself is always imported.
+//!     - `reexport`: Re-exports the generated structs alongside the enum.
 //! * `#[evt(implement_marker_traits(MarkerTrait1))]`: Generated structs all
 //!   `impl MarkerTrait1`.
+//! * `#[evt(impls(struct, from, try_from, markers))]`: Only generates the
+//!   listed items for each variant -- the struct declaration, `From`,
+//!   `TryFrom`, and marker trait impls. Defaults to all of them.
+//! * `#[evt(skip(try_from))]`: Does not generate the listed items for any
+//!   variant.
+//! * `#[evt(opt_in)]`: Only generates items for variants tagged with
+//!   `#[evt(include)]`, or with any other `evt` argument.
+//! * `#[evt(attr(serde(deny_unknown_fields), must_use))]`: Attaches the
+//!   attributes to **every** generated struct.
+//! * `#[evt(strict)]`: Rejects unknown `evt` arguments on variants, suggesting
+//!   the closest known argument. Attributes for the generated struct must be
+//!   wrapped in `attr(..)`. This is opt-in, as passing other arguments through
+//!   as attributes is supported for compatibility.
+//! * `#[evt(as_variant_ref)]`: Generates `as_variant_ref::<Struct>()` and
+//!   `as_variant_mut::<Struct>()` on a `#[repr(C)]` or `#[repr(C, Int)]` enum,
+//!   which borrow the variant's fields as the generated struct without moving
+//!   them. The layouts are checked at compile time, and only structs that are
+//!   exactly `repr(C)` with no skipped fields implement `VariantLayout`.
+//! * `#[evt(discriminant)]`: Generates `const DISCRIMINANT` on each struct. For
+//!   enums with a primitive representation such as `#[repr(u8)]`, also
+//!   generates `From<UnitStruct> for u8`, `TryFrom<u8> for MyEnum` over the
+//!   unit variants, and `MyEnum::discriminant(&self) -> u8`.
+//! * `#[evt(metadata)]`: Generates `VARIANT_NAME`, `VARIANT_INDEX`,
+//!   `FIELD_NAMES`, and `FIELD_TYPES` constants on each struct, and
+//!   `VARIANT_COUNT` and `VARIANTS: &[VariantInfo]` on the enum. `VARIANTS`
+//!   lists every variant, including those without a generated struct. Field
+//!   types are formatted by `stringify!`, e.g. `"Vec < u8 >"`.
+//! * `#[evt(fold)]`: Generates `MyEnum::fold(self, on_unit, on_tuple, ..)`,
+//!   which takes one `FnOnce(Struct) -> R` per variant in declaration order.
+//!   `fold_ref` and `fold_mut` pass `StructRef` and `StructMut` views of the
+//!   borrowed fields instead. Every variant must have a generated struct.
+//! * `#[evt(views)]`: Generates `StructRef` and `StructMut` views of each
+//!   variant's borrowed fields, with `TryFrom<&MyEnum>` and `TryFrom<&mut
+//!   MyEnum>` impls, and [`VariantView`] impls linking each struct to its
+//!   views. These are also generated by `#[evt(fold)]`.
+//! * `#[evt(map)]`: Generates `map_<variant>(self, f)` and
+//!   `and_then_<variant>(self, f)` for each variant, which transform the
+//!   variant's struct, or replace it with another enum value. Other variants
+//!   are returned unchanged. These use the generated `From` and `TryFrom`
+//!   impls, and are not generated for variants without them.
+//! * `#[evt(buckets)]`: Generates `MyEnumBuckets`, with one `Vec` per generated
+//!   struct named after the variant in `snake_case`, and `rest` for variants
+//!   without a struct. It implements `FromIterator<MyEnum>`, `Extend<MyEnum>`,
+//!   and `IntoIterator<Item = MyEnum>`, and requires the `alloc` feature. A
+//!   variant with a struct cannot be named `Rest`.
+//! * `#[evt(kind_set)]`: Generates `MyEnumKind`, a fieldless enum of the
+//!   variants, and `MyEnumSet`, a `Copy` bitset of kinds backed by the smallest
+//!   integer with a bit per variant. `MyEnumSet::of::<Tuple>()` is a `const
+//!   fn`, and the set supports `insert`, `remove`, `contains(&MyEnum)`,
+//!   `contains_kind`, set operations, and iteration.
+//! * `#[evt(variant_map)]`: Generates `MyEnumKind`, and `MyEnumVariantMap<V>`,
+//!   an array backed map with one value per variant. Values are accessed with
+//!   `get::<Tuple>()`, `get_for(&MyEnum)`, `get_mut::<Tuple>()`, or indexing by
+//!   kind, and `iter()` yields `(MyEnumKind, &V)`. It does not allocate.
+//! * `#[evt(serde_repr = "external")]`: Implements `Serialize` and
+//!   `Deserialize` for each struct, so that it has the same representation as
+//!   its enum variant. `"internal(tag)"` and `"adjacent(tag, content)"` match
+//!   enums with `#[serde(tag = "tag")]` and `#[serde(tag = "tag", content =
+//!   "content")]`. The enum's `rename` and `rename_all`, and the variants' and
+//!   fields' `#[serde(..)]` attributes are respected. Deserializing a different
+//!   variant is an error. Requires the `serde` feature.
+//! * `#[evt(constructors)]`: Generates `const fn new(field_0: u32, field_1:
+//!   u64) -> Struct` on each struct, and `MyEnum::new_struct(..)` for each
+//!   variant with a `From` impl. With `#[evt(constructors(into_args))]`, the
+//!   constructors take `impl Into<FieldTy>` for each field, and are not
+//!   `const`.
+//! * `#[evt(builder)]`: Generates `StructBuilder` for each named variant's
+//!   struct, with a chainable setter per field, and `Struct::builder()`.
+//!   `build()` returns the struct, or [`MissingField`] if a required field is
+//!   not set. Fields with an `Option` type default to `None`. `build_enum()`
+//!   returns the enum variant using the `From` impl. Fields cannot be named
+//!   `build`, or `build_enum` when the `From` impl is generated.
+//! * `#[evt(tuple_conv)]`: Generates `From<(u32, u64)> for Tuple`, `From<Tuple>
+//!   for (u32, u64)`, and `From<(u32, u64)> for MyEnum` for each struct with
+//!   fields, using the fields in declaration order. Variants whose fields may
+//!   have the same types cannot both have the `MyEnum` conversion, and fields
+//!   that mention the enum's generic parameters may have any type.
+//! * `#[evt(response_derive(Debug, PartialEq))]`: Derives the traits on the
+//!   `MyEnumResponse` enum generated by `#[evt(response = ..)]`, which has no
+//!   derives otherwise.
+//!
+//! Layout relevant hints of the enum's `#[repr(..)]` -- `C`, `transparent`,
+//! `packed`, and `align(N)` -- are propagated to the generated structs.
+//! Primitive representations such as `u8` are not, as they are only valid on
+//! enums.
+//!
+//! ### Additional options specified by an `evt` attribute on variant:
+//!
+//! * `#[evt(skip)]`: Skips generating a struct for the variant.
+//! * `#[evt(include)]`: Generates a struct for the variant when
+//!   `#[evt(opt_in)]` is specified on the enum.
+//! * `#[evt(repr(transparent))]`: Uses the given `repr` for the generated
+//!   struct instead of the one propagated from the enum.
+//! * `#[evt(impls(struct, from))]`: Only generates the listed items for the
+//!   variant, overriding the enum level `impls(..)`.
+//! * `#[evt(skip(try_from))]`: Does not generate the listed items for the
+//!   variant, e.g. to hand write a `TryFrom` impl that validates invariants.
+//! * `#[evt(module = "module1")]`: Places the generated struct into `mod
+//!   module1 { ... }`, relative to the enum level module if there is one.
+//!   Variants with the same module path share a single module.
+//! * `#[evt(no_attr(serde))]`: Does not attach the enum level `serde` attribute
+//!   to the generated struct. `#[evt(no_attr)]` does not attach any enum level
+//!   `attr(..)` attributes.
+//! * `#[evt(derive(Debug))]`, `#[evt(attr(serde(rename_all = "camelCase")))]`:
+//!   Attaches the attributes to the generated struct.
+//! * `#[evt(transition(to = Open, via = "open"))]`: Generates the
+//!   `ConnectingTransitions` trait for the variant's struct, with an
+//!   `open(self, ..)` method that returns an `Open`. Fields with the same name
+//!   are moved across, and the target's other fields are taken as parameters.
+//!   `MyEnum::transition(self, next)` returns `next`, or an
+//!   [`IllegalTransition`] if no transition is declared between the variants.
+//! * `#[evt(response = GetUserResp)]`: Implements [`Request`] for the variant's
+//!   struct with `type Response = GetUserResp`, and adds a variant holding
+//!   `GetUserResp` to the generated `MyEnumResponse` enum, which converts to
+//!   and from each response type. `MyEnum::call(request, transport)` passes the
+//!   request as `MyEnum` to a `FnOnce(MyEnum) -> MyEnumResponse` transport, and
+//!   returns its response as `Ok(R::Response)`, or the response in `Err` if it
+//!   answers a different request, instead of panicking. Response types must be
+//!   distinct.
+//! * `#[evt(tag = 0x12)]`: Gives the variant a stable `u16` tag, independent of
+//!   declaration order. Generates `const TAG` on the struct, `MyEnumKind`,
+//!   `MyEnum::tag(&self)`, and `MyEnum::kind_from_tag(u16)`. When any variant
+//!   has a tag, every variant needs one, and tags must be unique.
+//! * Without `#[evt(strict)]` on the enum, any other argument, such as
+//!   `#[evt(must_use)]`, is attached to the generated struct as an attribute.
+//!
+//! ### Additional options specified by an `evt` attribute on field:
+//!
+//! * `#[evt(rename = "field_name")]`: Names the field differently in the
+//!   generated struct. Only applicable to named fields.
+//! * `#[evt(attr(serde(default)))]`: Attaches the attributes to the field in
+//!   the generated struct.
+//! * `#[evt(strip_attrs)]`: Only copies `doc` and `cfg` attributes from the
+//!   variant field. `#[evt(strip_attrs(serde))]` does not copy `serde`
+//!   attributes.
+//! * `#[evt(skip)]`, `#[evt(skip, default = expr)]`: Leaves the field out of
+//!   the generated struct. When converting the struct into the enum, the field
+//!   is set to `expr`, or `Default::default()` if no expression is given.
+//!
+//! ### Iterator extensions
+//!
+//! [`VariantIterExt`] filters and partitions iterators of enums using the
+//! generated impls, e.g. `iter.variants::<Tuple>()`,
+//! `iter.variant_refs::<Tuple>()`, and
+//! `iter.partition_variant::<Tuple>()`. `variant_refs` yields the struct's
+//! views, so requires `#[evt(views)]`. This crate is `no_std`, and
+//! `partition_variant` requires the `alloc` feature, which is enabled by
+//! default.
 
+pub use enum_variant_type_derive::EnumVariantType;
+
+#[cfg(feature = "alloc")]
 extern crate alloc;
-extern crate proc_macro;
 
-use alloc::vec::Vec;
-use proc_macro::TokenStream;
-use proc_macro2::{Ident, Span};
-use proc_macro_roids::{namespace_parameters, FieldsExt};
-use quote::quote;
-use syn::{
-    parse_macro_input, parse_quote, Attribute, Data, DataEnum, DeriveInput, Field, Fields, LitStr,
-    Meta, Path,
+pub use crate::{
+    illegal_transition::IllegalTransition,
+    missing_field::MissingField,
+    request::Request,
+    variant_info::VariantInfo,
+    variant_iter::{VariantIterExt, Variants},
+    variant_kind::VariantKind,
+    variant_layout::{variant_mut, variant_ref, VariantLayout},
+    variant_view::VariantView,
 };
 
-/// Attributes that should be copied across.
-const ATTRIBUTES_TO_COPY: &[&str] = &["doc", "cfg", "allow", "deny"];
-
-/// Derives a struct for each enum variant.
-///
-/// Struct fields including their attributes are copied over.
-#[cfg(not(tarpaulin_include))]
-#[proc_macro_derive(EnumVariantType, attributes(evt))]
-pub fn enum_variant_type(input: TokenStream) -> TokenStream {
-    let ast = parse_macro_input!(input as DeriveInput);
-
-    // Need to do this, otherwise we can't unit test the input.
-    enum_variant_type_impl(ast).into()
-}
-
-#[inline]
-fn enum_variant_type_impl(ast: DeriveInput) -> proc_macro2::TokenStream {
-    let enum_name = &ast.ident;
-    let vis = &ast.vis;
-    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
-    let data_enum = data_enum(&ast);
-    let variants = &data_enum.variants;
-
-    let mut wrap_in_module = None::<Ident>;
-    let mut derive_for_all_variants = None::<Attribute>;
-    let mut marker_trait_paths = Vec::<Path>::new();
-    let mut repr_c = false;
-
-    for attr in ast.attrs.iter() {
-        if attr.path().is_ident("repr") {
-            // wrap each enum struct in "repr(C)" ?
-            if let Meta::List(list) = &attr.meta {
-                list.parse_nested_meta(|parse_nested_meta| {
-                    if parse_nested_meta.path.is_ident("C") {
-                        repr_c = true;
-                    }
-                    Ok(())
-                })
-                .unwrap_or_else(|e| panic!("Failed to parse repr attribute. Error: {}", e));
-            }
-        } else if attr.path().is_ident("evt") {
-            attr.parse_nested_meta(|nested_meta| {
-                if nested_meta.path.is_ident("module") {
-                    // `#[evt(module = \"some_module_name\")]`
-                    let module_name: LitStr = nested_meta
-                        .value()
-                        .and_then(|value| value.parse())
-                        .unwrap_or_else(|e| {
-                            panic!(
-                                "Expected `evt` attribute argument in the form: \
-                                    `#[evt(module = \"some_module_name\")]`. Error: {}",
-                                e
-                            )
-                        });
-
-                    wrap_in_module = Some(Ident::new(&module_name.value(), Span::call_site()));
-                    return Ok(());
-                }
-                // `#[evt(derive(Clone, Debug))]`
-                if nested_meta.path.is_ident("derive") {
-                    let mut items = Vec::new();
-                    nested_meta.parse_nested_meta(|parse_nested_meta| {
-                        items.push(parse_nested_meta.path);
-                        Ok(())
-                    })?;
-
-                    derive_for_all_variants = Some(parse_quote! {
-                        #[derive( #(#items),* )]
-                    });
-                    return Ok(());
-                }
-
-                // `#[evt(implement_marker_traits(MarkerTrait1, MarkerTrait2))]`
-                if nested_meta.path.is_ident("implement_marker_traits") {
-                    nested_meta.parse_nested_meta(|parse_nested_meta| {
-                        marker_trait_paths.push(parse_nested_meta.path);
-                        Ok(())
-                    })?;
-
-                    return Ok(());
-                }
-
-                panic!(
-                    "Unexpected usage of `evt` attribute, please see  examples at:\n\
-                        <https://docs.rs/enum_variant_type/>"
-                )
-            })
-            .unwrap_or_else(|e| {
-                panic!("Failed to process evt attribute. Error: {}", e);
-            });
-        }
-    }
-
-    let mut struct_declarations = proc_macro2::TokenStream::new();
-
-    let ns: Path = parse_quote!(evt);
-    let skip: Path = parse_quote!(skip);
-    let struct_declarations_iter = variants.iter()
-        .filter(|variant| !proc_macro_roids::contains_tag(&variant.attrs,  &ns, &skip))
-        .map(|variant| {
-
-        let variant_name = &variant.ident;
-        let attrs_to_copy = variant
-            .attrs
-            .iter()
-            .filter(|attribute| {
-                ATTRIBUTES_TO_COPY
-                    .iter()
-                    .any(|attr_to_copy| attribute.path().is_ident(attr_to_copy))
-            })
-            .collect::<Vec<&Attribute>>();
-
-        let evt_meta_lists = namespace_parameters(&variant.attrs, &ns);
-        let mut variant_struct_attrs = evt_meta_lists
-            .into_iter()
-            .fold(
-                proc_macro2::TokenStream::new(),
-                |mut attrs_tokens, variant_struct_attr| {
-                    attrs_tokens.extend(quote!(#[#variant_struct_attr]));
-                    attrs_tokens
-                },
-            );
-
-        if repr_c {
-            variant_struct_attrs.extend(quote! {
-                 #[repr(C)]
-            })
-        }
-
-        let variant_fields = &variant.fields;
-
-        // Need to attach visibility modifier to fields.
-        let fields_with_vis = variant_fields
-            .iter()
-            .cloned()
-            .map(|mut field| {
-                field.vis = vis.clone();
-                field
-            })
-            .collect::<Vec<Field>>();
-
-        let data_struct = match variant_fields {
-            Fields::Unit => quote! {
-                struct #variant_name;
-            },
-            Fields::Unnamed(..) => {
-                quote! {
-                    struct #variant_name #ty_generics (#(#fields_with_vis,)*) #where_clause;
-                }
-            }
-            Fields::Named(..) => quote! {
-                struct #variant_name #ty_generics #where_clause {
-                    #(#fields_with_vis,)*
-                }
-            },
-        };
-
-        // TODO: This generates invalid code if the type parameter is not used by this variant.
-        let construction_form = variant_fields.construction_form();
-        let deconstruct_variant_struct = if variant_fields.is_unit() {
-            proc_macro2::TokenStream::new()
-        } else {
-            quote! {
-                let #variant_name #construction_form = variant_struct;
-            }
-        };
-        let impl_from_variant_for_enum = quote! {
-            impl #impl_generics core::convert::From<#variant_name #ty_generics>
-                for #enum_name #ty_generics
-            #where_clause {
-                fn from(variant_struct: #variant_name #ty_generics) -> Self {
-                    // Deconstruct the parameter.
-                    #deconstruct_variant_struct
-
-                    #enum_name::#variant_name #construction_form
-                }
-            }
-        };
-
-        let impl_try_from_enum_for_variant = quote! {
-            impl #impl_generics core::convert::TryFrom<#enum_name #ty_generics>
-                for #variant_name #ty_generics
-            #where_clause {
-                type Error = #enum_name #ty_generics;
-
-                fn try_from(enum_variant: #enum_name #ty_generics) -> Result<Self, Self::Error> {
-                    // Deconstruct the variant.
-                    if let #enum_name::#variant_name #construction_form = enum_variant {
-                        core::result::Result::Ok(#variant_name #construction_form)
-                    } else {
-                        core::result::Result::Err(enum_variant)
-                    }
-                }
-            }
-        };
-
-        quote! {
-            #(#attrs_to_copy)*
-            #derive_for_all_variants
-            #variant_struct_attrs
-            #vis #data_struct
-
-            #impl_from_variant_for_enum
-
-            #impl_try_from_enum_for_variant
-
-            #(impl #ty_generics #marker_trait_paths for #variant_name #ty_generics {})*
-        }
-    });
-    struct_declarations.extend(struct_declarations_iter);
-
-    if let Some(module_to_wrap_in) = wrap_in_module {
-        quote! {
-            #vis mod #module_to_wrap_in {
-                use super::*;
-
-                #struct_declarations
-            }
-        }
-    } else {
-        struct_declarations
-    }
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::vec::{self, Vec};
+    #[cfg(feature = "serde")]
+    pub use serde;
 }
 
-fn data_enum(ast: &DeriveInput) -> &DataEnum {
-    if let Data::Enum(data_enum) = &ast.data {
-        data_enum
-    } else {
-        panic!("`EnumVariantType` derive can only be used on an enum.");
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    extern crate alloc;
-
-    use alloc::string::ToString;
-    use pretty_assertions::assert_eq;
-    use quote::quote;
-    use syn::{parse_quote, DeriveInput};
-
-    use super::enum_variant_type_impl;
-
-    #[test]
-    fn generates_correct_tokens_for_basic_enum() {
-        let ast: DeriveInput = parse_quote! {
-            pub enum MyEnum {
-                /// Unit variant.
-                #[evt(derive(Clone, Copy, Debug, PartialEq))]
-                Unit,
-                /// Tuple variant.
-                #[evt(derive(Debug))]
-                Tuple(u32, u64),
-                /// Struct variant.
-                Struct {
-                    field_0: u32,
-                    field_1: u64,
-                },
-            }
-        };
-
-        let actual_tokens = enum_variant_type_impl(ast);
-        let expected_tokens = quote! {
-            /// Unit variant.
-            #[derive(Clone, Copy, Debug, PartialEq)]
-            pub struct Unit;
-
-            impl core::convert::From<Unit> for MyEnum {
-                fn from(variant_struct: Unit) -> Self {
-                    MyEnum::Unit
-                }
-            }
-
-            impl core::convert::TryFrom<MyEnum> for Unit {
-                type Error = MyEnum;
-                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
-                    if let MyEnum::Unit = enum_variant {
-                        core::result::Result::Ok(Unit)
-                    } else {
-                        core::result::Result::Err(enum_variant)
-                    }
-                }
-            }
-
-            /// Tuple variant.
-            #[derive(Debug)]
-            pub struct Tuple(pub u32, pub u64,);
-
-            impl core::convert::From<Tuple> for MyEnum {
-                fn from(variant_struct: Tuple) -> Self {
-                    let Tuple(_0, _1,) = variant_struct;
-                    MyEnum::Tuple(_0, _1,)
-                }
-            }
-
-            impl core::convert::TryFrom<MyEnum> for Tuple {
-                type Error = MyEnum;
-                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
-                    if let MyEnum::Tuple(_0, _1,) = enum_variant {
-                        core::result::Result::Ok(Tuple(_0, _1,))
-                    } else {
-                        core::result::Result::Err(enum_variant)
-                    }
-                }
-            }
-
-            /// Struct variant.
-            pub struct Struct {
-                pub field_0: u32,
-                pub field_1: u64,
-            }
-
-            impl core::convert::From<Struct> for MyEnum {
-                fn from(variant_struct: Struct) -> Self {
-                    let Struct { field_0, field_1, } = variant_struct;
-                    MyEnum::Struct { field_0, field_1, }
-                }
-            }
-
-            impl core::convert::TryFrom<MyEnum> for Struct {
-                type Error = MyEnum;
-                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
-                    if let MyEnum::Struct { field_0, field_1, } = enum_variant {
-                        core::result::Result::Ok(Struct { field_0, field_1, })
-                    } else {
-                        core::result::Result::Err(enum_variant)
-                    }
-                }
-            }
-        };
-
-        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
-    }
-
-    #[test]
-    fn skips_variants_marked_with_evt_skip() {
-        let ast: DeriveInput = parse_quote! {
-            pub enum MyEnum {
-                /// Unit variant.
-                #[evt(derive(Clone, Copy, Debug, PartialEq))]
-                Unit,
-                /// Skipped variant.
-                #[evt(skip)]
-                UnitSkipped,
-            }
-        };
-
-        let actual_tokens = enum_variant_type_impl(ast);
-        let expected_tokens = quote! {
-            /// Unit variant.
-            #[derive(Clone, Copy, Debug, PartialEq)]
-            pub struct Unit;
-
-            impl core::convert::From<Unit> for MyEnum {
-                fn from(variant_struct: Unit) -> Self {
-                    MyEnum::Unit
-                }
-            }
-
-            impl core::convert::TryFrom<MyEnum> for Unit {
-                type Error = MyEnum;
-                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
-                    if let MyEnum::Unit = enum_variant {
-                        core::result::Result::Ok(Unit)
-                    } else {
-                        core::result::Result::Err(enum_variant)
-                    }
-                }
-            }
-        };
-
-        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
-    }
-
-    #[test]
-    fn put_variants_in_module() {
-        let ast: DeriveInput = parse_quote! {
-            #[evt(module = "example")]
-            pub enum MyEnum {
-                A,
-                B
-            }
-        };
-
-        let actual_tokens = enum_variant_type_impl(ast);
-        let expected_tokens = quote! {
-            pub mod example {
-                use super::*;
-
-                pub struct A;
-
-                impl core::convert::From<A> for MyEnum {
-                    fn from(variant_struct: A) -> Self {
-                        MyEnum::A
-                    }
-                }
-
-                impl core::convert::TryFrom<MyEnum> for A {
-                    type Error = MyEnum;
-                    fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
-                        if let MyEnum::A = enum_variant {
-                            core::result::Result::Ok(A)
-                        } else {
-                            core::result::Result::Err(enum_variant)
-                        }
-                    }
-                }
-
-                pub struct B;
-
-                impl core::convert::From<B> for MyEnum {
-                    fn from(variant_struct: B) -> Self {
-                        MyEnum::B
-                    }
-                }
-
-                impl core::convert::TryFrom<MyEnum> for B {
-                    type Error = MyEnum;
-                    fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
-                        if let MyEnum::B = enum_variant {
-                            core::result::Result::Ok(B)
-                        } else {
-                            core::result::Result::Err(enum_variant)
-                        }
-                    }
-                }
-            }
-        };
-
-        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
-    }
-
-    #[test]
-    fn derive_traits_for_all_variants() {
-        let ast: DeriveInput = parse_quote! {
-            #[evt(derive(Debug))]
-            pub enum MyEnum {
-                A,
-                #[evt(derive(Clone))]
-                B
-            }
-        };
-
-        let actual_tokens = enum_variant_type_impl(ast);
-        let expected_tokens = quote! {
-            #[derive(Debug)]
-            pub struct A;
-
-            impl core::convert::From<A> for MyEnum {
-                fn from(variant_struct: A) -> Self {
-                    MyEnum::A
-                }
-            }
-
-            impl core::convert::TryFrom<MyEnum> for A {
-                type Error = MyEnum;
-                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
-                    if let MyEnum::A = enum_variant {
-                        core::result::Result::Ok(A)
-                    } else {
-                        core::result::Result::Err(enum_variant)
-                    }
-                }
-            }
-
-            #[derive(Debug)]
-            #[derive(Clone)]
-            pub struct B;
-
-            impl core::convert::From<B> for MyEnum {
-                fn from(variant_struct: B) -> Self {
-                    MyEnum::B
-                }
-            }
-
-            impl core::convert::TryFrom<MyEnum> for B {
-                type Error = MyEnum;
-                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
-                    if let MyEnum::B = enum_variant {
-                        core::result::Result::Ok(B)
-                    } else {
-                        core::result::Result::Err(enum_variant)
-                    }
-                }
-            }
-        };
-
-        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
-    }
-
-    #[test]
-    fn derive_marker_trait() {
-        let ast: DeriveInput = parse_quote! {
-            #[evt(implement_marker_traits(MarkerTrait1))]
-            pub enum MyEnum {
-                A,
-                B
-            }
-        };
-
-        let actual_tokens = enum_variant_type_impl(ast);
-        let expected_tokens = quote! {
-            pub struct A;
-
-            impl core::convert::From<A> for MyEnum {
-                fn from(variant_struct: A) -> Self {
-                    MyEnum::A
-                }
-            }
-
-            impl core::convert::TryFrom<MyEnum> for A {
-                type Error = MyEnum;
-                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
-                    if let MyEnum::A = enum_variant {
-                        core::result::Result::Ok(A)
-                    } else {
-                        core::result::Result::Err(enum_variant)
-                    }
-                }
-            }
-
-            impl MarkerTrait1 for A {}
-
-            pub struct B;
-
-            impl core::convert::From<B> for MyEnum {
-                fn from(variant_struct: B) -> Self {
-                    MyEnum::B
-                }
-            }
-
-            impl core::convert::TryFrom<MyEnum> for B {
-                type Error = MyEnum;
-                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
-                    if let MyEnum::B = enum_variant {
-                        core::result::Result::Ok(B)
-                    } else {
-                        core::result::Result::Err(enum_variant)
-                    }
-                }
-            }
-
-            impl MarkerTrait1 for B {}
-        };
-
-        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
-    }
-
-    #[test]
-    fn derive_marker_repr() {
-        let ast: DeriveInput = parse_quote! {
-            #[derive(Debug)]
-            #[repr(C)]
-            pub enum MyEnum {
-                A { i: i64 },
-                B { i: i64 },
-            }
-        };
-
-        let actual_tokens = enum_variant_type_impl(ast);
-        let expected_tokens = quote! {
-
-            #[repr(C)]
-            pub struct A { pub i: i64, }
-
-            impl core::convert::From<A> for MyEnum {
-                fn from(variant_struct: A) -> Self {
-                    let A { i, } = variant_struct;
-                    MyEnum::A { i, }
-                }
-            }
-
-            impl core::convert::TryFrom<MyEnum> for A {
-                type Error = MyEnum;
-                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
-                    if let MyEnum::A { i, } = enum_variant {
-                        core::result::Result::Ok(A { i, })
-                    } else {
-                        core::result::Result::Err(enum_variant)
-                    }
-                }
-            }
-
-            #[repr(C)]
-            pub struct B { pub i: i64, }
-
-            impl core::convert::From<B> for MyEnum {
-                fn from(variant_struct: B) -> Self {
-                    let B { i, } = variant_struct;
-                    MyEnum::B { i, }
-                }
-            }
-
-            impl core::convert::TryFrom<MyEnum> for B {
-                type Error = MyEnum;
-                fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
-                    if let MyEnum::B { i, } = enum_variant {
-                        core::result::Result::Ok(B { i, })
-                    } else {
-                        core::result::Result::Err(enum_variant)
-                    }
-                }
-            }
-        };
-
-        assert_eq!(expected_tokens.to_string(), actual_tokens.to_string());
-    }
-}
+mod illegal_transition;
+mod missing_field;
+mod request;
+mod variant_info;
+mod variant_iter;
+mod variant_kind;
+mod variant_layout;
+mod variant_view;
@@ -0,0 +1,26 @@
+use core::fmt;
+
+/// Error returned by a generated `StructBuilder` when a required field is not
+/// set.
+///
+/// Generated by `#[evt(builder)]`. Fields with an `Option` type are not
+/// required, and default to `None`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MissingField {
+    /// Name of the struct being built.
+    pub struct_name: &'static str,
+    /// Name of the field that is not set.
+    pub field_name: &'static str,
+}
+
+impl fmt::Display for MissingField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is missing the required field `{}`",
+            self.struct_name, self.field_name
+        )
+    }
+}
+
+impl core::error::Error for MissingField {}
@@ -0,0 +1,12 @@
+/// Links a generated struct to the response it is answered with.
+///
+/// Implemented by `#[evt(response = Type)]` on a variant, which also generates
+/// the `MyEnumResponse` enum with one variant per response, and
+/// `MyEnum::call`, which sends a request through a transport closure and
+/// converts the response back.
+pub trait Request: Into<Self::Enum> {
+    /// The enum this request is a variant of, e.g. `MyEnum`.
+    type Enum;
+    /// The type of the response to this request.
+    type Response;
+}
@@ -0,0 +1,17 @@
+/// Compile time metadata about an enum variant.
+///
+/// Generated for every variant by `#[evt(metadata)]` as `MyEnum::VARIANTS`,
+/// including variants that do not have a generated struct.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VariantInfo {
+    /// Name of the variant.
+    pub name: &'static str,
+    /// Position of the variant in the enum's declaration.
+    pub index: usize,
+    /// Names of the variant's fields, or their indices for tuple variants.
+    pub field_names: &'static [&'static str],
+    /// Types of the variant's fields, formatted by `stringify!`.
+    pub field_types: &'static [&'static str],
+    /// Whether a struct is generated for the variant.
+    pub has_struct: bool,
+}
@@ -0,0 +1,131 @@
+use core::{convert::TryFrom, iter::FusedIterator, marker::PhantomData};
+
+use crate::VariantView;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Extension methods to filter and partition iterators by variant.
+///
+/// These use the generated `TryFrom` impls, so `variants::<Tuple>()` yields
+/// each `MyEnum::Tuple` as a `Tuple`.
+///
+/// # Examples
+///
+/// ```rust
+/// use enum_variant_type::{EnumVariantType, VariantIterExt};
+///
+/// #[derive(Debug, EnumVariantType)]
+/// #[evt(derive(Debug, PartialEq), views)]
+/// pub enum MyEnum {
+///     Unit,
+///     Tuple(u32),
+/// }
+///
+/// let my_enums = vec![MyEnum::Tuple(1), MyEnum::Unit, MyEnum::Tuple(2)];
+///
+/// let values = my_enums
+///     .iter()
+///     .variant_refs::<Tuple>()
+///     .map(|TupleRef(value)| *value)
+///     .collect::<Vec<u32>>();
+/// assert_eq!(vec![1, 2], values);
+///
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// let (tuples, rest) = my_enums.into_iter().partition_variant::<Tuple>();
+/// assert_eq!(vec![Tuple(1), Tuple(2)], tuples);
+/// assert_eq!(1, rest.len());
+/// # }
+/// ```
+pub trait VariantIterExt: Iterator + Sized {
+    /// Returns an iterator over the items that are the variant `V`, converted
+    /// into `V`.
+    fn variants<V>(self) -> Variants<Self, V>
+    where
+        V: TryFrom<Self::Item>,
+    {
+        Variants {
+            iter: self,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the borrowed items that are the variant `V`,
+    /// as borrowed views such as `TupleRef` or `TupleMut`.
+    ///
+    /// `V` is the generated struct, e.g. `variant_refs::<Tuple>()`. Views are
+    /// generated by `#[evt(views)]`.
+    fn variant_refs<V>(self) -> Variants<Self, V::View>
+    where
+        Self::Item: sealed::Borrowed,
+        V: VariantView<Self::Item>,
+    {
+        Variants {
+            iter: self,
+            marker: PhantomData,
+        }
+    }
+
+    /// Splits the items into those that are the variant `V`, and the rest.
+    #[cfg(feature = "alloc")]
+    fn partition_variant<V>(self) -> (Vec<V>, Vec<V::Error>)
+    where
+        V: TryFrom<Self::Item>,
+    {
+        let mut variants = Vec::new();
+        let mut rest = Vec::new();
+        self.for_each(|item| match V::try_from(item) {
+            Ok(variant) => variants.push(variant),
+            Err(item) => rest.push(item),
+        });
+
+        (variants, rest)
+    }
+}
+
+impl<I> VariantIterExt for I where I: Iterator {}
+
+/// Iterator over the items that are a particular variant.
+///
+/// Returned by [`VariantIterExt::variants`] and
+/// [`VariantIterExt::variant_refs`].
+#[derive(Clone, Debug)]
+pub struct Variants<I, V> {
+    /// The underlying iterator.
+    iter: I,
+    /// The variant to yield.
+    marker: PhantomData<fn() -> V>,
+}
+
+impl<I, V> Iterator for Variants<I, V>
+where
+    I: Iterator,
+    V: TryFrom<I::Item>,
+{
+    type Item = V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.by_ref().find_map(|item| V::try_from(item).ok())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, V> FusedIterator for Variants<I, V>
+where
+    I: FusedIterator,
+    V: TryFrom<I::Item>,
+{
+}
+
+mod sealed {
+    /// Implemented for references, so that `variant_refs` is only used with
+    /// borrowing iterators.
+    pub trait Borrowed {}
+
+    impl<T> Borrowed for &T where T: ?Sized {}
+    impl<T> Borrowed for &mut T where T: ?Sized {}
+}
@@ -0,0 +1,11 @@
+/// Links a generated struct to its variant's kind.
+///
+/// Implemented by `#[evt(kind_set)]` and `#[evt(variant_map)]`, which also
+/// generate the `MyEnumKind` enum with one fieldless variant per enum variant.
+pub trait VariantKind {
+    /// The generated kind enum, e.g. `MyEnumKind`.
+    type Kind;
+
+    /// The kind of the variant this struct is generated from.
+    const KIND: Self::Kind;
+}
@@ -0,0 +1,64 @@
+/// Marks a generated struct as having the same layout as its variant's fields
+/// within the enum `E`.
+///
+/// This is implemented by `#[evt(as_variant_ref)]` for `repr(C)` enums, along
+/// with compile time assertions that the layouts match.
+///
+/// # Safety
+///
+/// Implementors must guarantee that when [`is_variant`] returns `true`, the
+/// enum's bytes at [`PAYLOAD_OFFSET`] are a valid `Self`, and that `Self` does
+/// not extend past the variant's fields.
+///
+/// [`is_variant`]: VariantLayout::is_variant
+/// [`PAYLOAD_OFFSET`]: VariantLayout::PAYLOAD_OFFSET
+pub unsafe trait VariantLayout<E>: Sized {
+    /// Offset of the variant's fields from the start of the enum.
+    const PAYLOAD_OFFSET: usize;
+
+    /// Returns whether the enum is this variant.
+    fn is_variant(enum_variant: &E) -> bool;
+}
+
+/// Returns a reference to the variant's fields as `V`, or `None` if the enum
+/// is a different variant.
+pub fn variant_ref<E, V>(enum_variant: &E) -> Option<&V>
+where
+    V: VariantLayout<E>,
+{
+    if V::is_variant(enum_variant) {
+        let payload = (enum_variant as *const E)
+            .cast::<u8>()
+            .wrapping_add(V::PAYLOAD_OFFSET)
+            .cast::<V>();
+
+        // SAFETY: `VariantLayout` guarantees the payload is a valid `V` for this
+        // variant, and it is borrowed for as long as the enum is.
+        Some(unsafe { &*payload })
+    } else {
+        None
+    }
+}
+
+/// Returns a mutable reference to the variant's fields as `V`, or `None` if the
+/// enum is a different variant.
+///
+/// The tag is not part of `V`, so writes through the reference cannot change
+/// which variant the enum is.
+pub fn variant_mut<E, V>(enum_variant: &mut E) -> Option<&mut V>
+where
+    V: VariantLayout<E>,
+{
+    if V::is_variant(enum_variant) {
+        let payload = (enum_variant as *mut E)
+            .cast::<u8>()
+            .wrapping_add(V::PAYLOAD_OFFSET)
+            .cast::<V>();
+
+        // SAFETY: `VariantLayout` guarantees the payload is a valid `V` for this
+        // variant, and it is exclusively borrowed for as long as the enum is.
+        Some(unsafe { &mut *payload })
+    } else {
+        None
+    }
+}
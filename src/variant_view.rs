@@ -0,0 +1,16 @@
+use core::convert::TryFrom;
+
+/// Links a generated struct to its borrowed view for a borrow of the enum.
+///
+/// Implemented by `#[evt(views)]` and `#[evt(fold)]` for `&MyEnum`, where the
+/// view is `StructRef`, and for `&mut MyEnum`, where the view is `StructMut`.
+/// Unit structs are their own view.
+///
+/// This lets [`VariantIterExt::variant_refs`] take the struct, e.g.
+/// `variant_refs::<Tuple>()`.
+///
+/// [`VariantIterExt::variant_refs`]: crate::VariantIterExt::variant_refs
+pub trait VariantView<Borrowed> {
+    /// The view of the variant's borrowed fields, e.g. `TupleRef<'evt>`.
+    type View: TryFrom<Borrowed>;
+}
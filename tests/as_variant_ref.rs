@@ -0,0 +1,71 @@
+use enum_variant_type::EnumVariantType;
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq), as_variant_ref)]
+#[repr(C)]
+pub enum MyEnum {
+    Unit,
+    Tuple(u8, u64),
+    Struct { name: String, value: u16 },
+}
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq), as_variant_ref, module = "tagged")]
+#[repr(C, u8)]
+pub enum Tagged {
+    A(u32) = 3,
+    B { value: [u8; 3] },
+}
+
+#[test]
+fn as_variant_ref_returns_fields_for_matching_variant() {
+    let my_enum = MyEnum::Struct {
+        name: String::from("a"),
+        value: 2,
+    };
+
+    assert_eq!(
+        Some(&Struct {
+            name: String::from("a"),
+            value: 2,
+        }),
+        my_enum.as_variant_ref::<Struct>()
+    );
+    assert_eq!(
+        Some(&Tuple(1, 2)),
+        MyEnum::Tuple(1, 2).as_variant_ref::<Tuple>()
+    );
+    assert_eq!(Some(&Unit), MyEnum::Unit.as_variant_ref::<Unit>());
+}
+
+#[test]
+fn as_variant_ref_returns_none_for_other_variants() {
+    assert_eq!(None, MyEnum::Unit.as_variant_ref::<Tuple>());
+    assert_eq!(None, MyEnum::Tuple(1, 2).as_variant_ref::<Struct>());
+    assert_eq!(None, Tagged::A(1).as_variant_ref::<tagged::B>());
+}
+
+#[test]
+fn as_variant_mut_writes_through_to_enum() {
+    let mut my_enum = MyEnum::Struct {
+        name: String::from("a"),
+        value: 2,
+    };
+
+    if let Some(variant) = my_enum.as_variant_mut::<Struct>() {
+        variant.name.push('b');
+        variant.value = 3;
+    }
+    assert_eq!(
+        MyEnum::Struct {
+            name: String::from("ab"),
+            value: 3,
+        },
+        my_enum
+    );
+
+    let mut tagged = Tagged::B { value: [1, 2, 3] };
+    *tagged.as_variant_mut::<tagged::B>().unwrap() = tagged::B { value: [4, 5, 6] };
+    assert_eq!(Tagged::B { value: [4, 5, 6] }, tagged);
+    assert_eq!(None, tagged.as_variant_mut::<tagged::A>());
+}
@@ -0,0 +1,29 @@
+use enum_variant_type::EnumVariantType;
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq), attr(derive(Default), must_use))]
+pub enum MyEnum {
+    Unit,
+    Tuple(u32, u64),
+    #[evt(no_attr(derive), derive(Clone))]
+    Struct {
+        field_0: u32,
+    },
+}
+
+#[test]
+fn enum_level_attributes_are_attached_to_every_struct() {
+    assert_eq!(MyEnum::Unit, MyEnum::from(default::<Unit>()));
+    assert_eq!(MyEnum::Tuple(0, 0), MyEnum::from(default::<Tuple>()));
+}
+
+#[test]
+fn variant_level_no_attr_removes_enum_level_attributes() {
+    let named = Struct { field_0: 1 };
+
+    assert_eq!(MyEnum::Struct { field_0: 1 }, MyEnum::from(named.clone()));
+}
+
+fn default<T: Default>() -> T {
+    T::default()
+}
@@ -0,0 +1,91 @@
+use enum_variant_type::EnumVariantType;
+
+#[derive(Clone, Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Clone, Debug, PartialEq), buckets)]
+pub enum MyEnum {
+    Unit,
+    Tuple(u32, u64),
+    Struct {
+        value: String,
+    },
+    #[evt(skip)]
+    Skipped,
+}
+
+fn my_enums() -> Vec<MyEnum> {
+    vec![
+        MyEnum::Tuple(1, 2),
+        MyEnum::Skipped,
+        MyEnum::Unit,
+        MyEnum::Tuple(3, 4),
+        MyEnum::Struct {
+            value: String::from("a"),
+        },
+    ]
+}
+
+#[test]
+fn from_iter_sorts_values_into_buckets() {
+    let buckets = my_enums().into_iter().collect::<MyEnumBuckets>();
+
+    assert_eq!(vec![Unit], buckets.unit);
+    assert_eq!(vec![Tuple(1, 2), Tuple(3, 4)], buckets.tuple);
+    assert_eq!(
+        vec![Struct {
+            value: String::from("a"),
+        }],
+        buckets.r#struct
+    );
+    assert_eq!(vec![MyEnum::Skipped], buckets.rest);
+    assert_eq!(5, buckets.len());
+}
+
+#[test]
+fn push_and_extend_add_to_buckets() {
+    let mut buckets = MyEnumBuckets::default();
+    assert!(buckets.is_empty());
+
+    buckets.push(MyEnum::Unit);
+    buckets.extend(vec![MyEnum::Tuple(1, 2), MyEnum::Skipped]);
+
+    assert_eq!(1, buckets.unit.len());
+    assert_eq!(1, buckets.tuple.len());
+    assert_eq!(1, buckets.rest.len());
+    assert_eq!(3, buckets.len());
+}
+
+#[test]
+fn into_iter_returns_values_in_variant_order() {
+    let buckets = my_enums().into_iter().collect::<MyEnumBuckets>();
+
+    assert_eq!(
+        vec![
+            MyEnum::Unit,
+            MyEnum::Tuple(1, 2),
+            MyEnum::Tuple(3, 4),
+            MyEnum::Struct {
+                value: String::from("a"),
+            },
+            MyEnum::Skipped,
+        ],
+        buckets.into_iter().collect::<Vec<_>>()
+    );
+}
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq), buckets, module = "generic")]
+pub enum Generic<T> {
+    Value(T),
+    Pair(T, T),
+}
+
+#[test]
+fn buckets_support_generic_enums_without_rest() {
+    let buckets = vec![Generic::Value(1u8), Generic::Pair(2, 3)]
+        .into_iter()
+        .collect::<GenericBuckets<u8>>();
+
+    assert_eq!(vec![generic::Value(1)], buckets.value);
+    assert_eq!(vec![generic::Pair(2, 3)], buckets.pair);
+    assert!(buckets.rest.is_empty());
+}
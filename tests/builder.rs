@@ -0,0 +1,128 @@
+use enum_variant_type::{EnumVariantType, MissingField};
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq), builder)]
+pub enum MyEnum {
+    Unit,
+    Tuple(u32),
+    Config {
+        name: String,
+        retries: u8,
+        timeout: Option<u64>,
+        #[evt(rename = "label")]
+        tag: core::option::Option<String>,
+    },
+    #[evt(skip(from))]
+    NoFrom {
+        value: Vec<u8>,
+        build_enum: bool,
+    },
+    Named {
+        builder: u8,
+    },
+}
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq), module = "generic", builder)]
+pub enum Generic<T> {
+    Pair { first: T, second: Option<T> },
+}
+
+#[test]
+fn build_sets_fields_and_defaults_options() {
+    let config = Config::builder().name(String::from("a")).retries(3).build();
+
+    assert_eq!(
+        Ok(Config {
+            name: String::from("a"),
+            retries: 3,
+            timeout: None,
+            label: None,
+        }),
+        config
+    );
+}
+
+#[test]
+fn build_sets_optional_fields() {
+    let config = ConfigBuilder::default()
+        .label(Some(String::from("l")))
+        .retries(1)
+        .timeout(Some(5))
+        .name(String::from("b"))
+        .build();
+
+    assert_eq!(
+        Ok(Config {
+            name: String::from("b"),
+            retries: 1,
+            timeout: Some(5),
+            label: Some(String::from("l")),
+        }),
+        config
+    );
+}
+
+#[test]
+fn build_returns_missing_field() {
+    let config = Config::builder().name(String::from("a")).build();
+
+    assert_eq!(
+        Err(MissingField {
+            struct_name: "Config",
+            field_name: "retries",
+        }),
+        config
+    );
+    assert_eq!(
+        "`Config` is missing the required field `retries`",
+        config.unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn build_enum_converts_into_the_enum() {
+    let my_enum = Config::builder()
+        .name(String::from("a"))
+        .retries(3)
+        .timeout(Some(1))
+        .build_enum();
+
+    assert_eq!(
+        Ok(MyEnum::Config {
+            name: String::from("a"),
+            retries: 3,
+            timeout: Some(1),
+            tag: None,
+        }),
+        my_enum
+    );
+    assert_eq!(
+        Ok(NoFrom {
+            value: vec![1],
+            build_enum: true,
+        }),
+        NoFrom::builder().value(vec![1]).build_enum(true).build()
+    );
+}
+
+#[test]
+fn setters_may_share_names_with_methods_that_are_not_on_the_builder() {
+    assert_eq!(
+        Ok(Named { builder: 1 }),
+        Named::builder().builder(1).build()
+    );
+}
+
+#[test]
+fn builders_support_generic_enums() {
+    let pair = generic::Pair::builder().first(1u8).build_enum();
+
+    assert_eq!(
+        Ok(Generic::Pair {
+            first: 1,
+            second: None
+        }),
+        pair
+    );
+}
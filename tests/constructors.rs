@@ -0,0 +1,72 @@
+use enum_variant_type::EnumVariantType;
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq), constructors)]
+pub enum MyEnum {
+    Unit,
+    Tuple(u32, u64),
+    Struct {
+        field_0: u32,
+        #[evt(rename = "second")]
+        field_1: u64,
+    },
+    #[evt(skip(from))]
+    NoFrom(u8),
+}
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(
+    derive(Debug, PartialEq),
+    module = "into_args",
+    constructors(into_args)
+)]
+pub enum IntoArgs {
+    Named { name: String, count: u64 },
+}
+
+const TUPLE: Tuple = Tuple::new(1, 2);
+
+#[test]
+fn struct_constructors_are_const() {
+    assert_eq!(Tuple(1, 2), TUPLE);
+    assert_eq!(Unit, Unit::new());
+    assert_eq!(
+        Struct {
+            field_0: 1,
+            second: 2
+        },
+        Struct::new(1, 2)
+    );
+    assert_eq!(NoFrom(3), NoFrom::new(3));
+}
+
+#[test]
+fn enum_constructors_build_variants() {
+    assert_eq!(MyEnum::Unit, MyEnum::new_unit());
+    assert_eq!(MyEnum::Tuple(1, 2), MyEnum::new_tuple(1, 2));
+    assert_eq!(
+        MyEnum::Struct {
+            field_0: 1,
+            field_1: 2
+        },
+        MyEnum::new_struct(1, 2)
+    );
+}
+
+#[test]
+fn into_args_constructors_convert_arguments() {
+    assert_eq!(
+        into_args::Named {
+            name: String::from("a"),
+            count: 3,
+        },
+        into_args::Named::new("a", 3u8)
+    );
+    assert_eq!(
+        IntoArgs::Named {
+            name: String::from("b"),
+            count: 4,
+        },
+        IntoArgs::new_named("b", 4u32)
+    );
+}
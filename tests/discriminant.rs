@@ -0,0 +1,59 @@
+use core::convert::TryFrom;
+
+use enum_variant_type::EnumVariantType;
+
+const BASE: u8 = 10;
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(discriminant)]
+#[repr(u8)]
+pub enum Opcode {
+    Nop = 5,
+    Halt,
+    Push(u32) = BASE,
+    Jump { target: u16 },
+    Ret = 20,
+}
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(discriminant, module = "level")]
+pub enum Level {
+    Low = -1,
+    Medium,
+    High = 8,
+}
+
+#[test]
+fn discriminant_constant_is_generated_on_each_struct() {
+    assert_eq!(5, Nop::DISCRIMINANT);
+    assert_eq!(6, Halt::DISCRIMINANT);
+    assert_eq!(10, Push::DISCRIMINANT);
+    assert_eq!(11, Jump::DISCRIMINANT);
+    assert_eq!(20, Ret::DISCRIMINANT);
+
+    assert_eq!(-1isize, level::Low::DISCRIMINANT);
+    assert_eq!(0isize, level::Medium::DISCRIMINANT);
+    assert_eq!(8isize, level::High::DISCRIMINANT);
+}
+
+#[test]
+fn unit_struct_converts_into_discriminant() {
+    assert_eq!(5u8, u8::from(Nop));
+    assert_eq!(20u8, u8::from(Ret));
+}
+
+#[test]
+fn enum_try_from_discriminant_covers_unit_variants() {
+    assert_eq!(Ok(Opcode::Nop), Opcode::try_from(5));
+    assert_eq!(Ok(Opcode::Halt), Opcode::try_from(6));
+    assert_eq!(Ok(Opcode::Ret), Opcode::try_from(20));
+    assert_eq!(Err(10), Opcode::try_from(10));
+    assert_eq!(Err(0), Opcode::try_from(0));
+}
+
+#[test]
+fn discriminant_is_read_for_field_carrying_variants() {
+    assert_eq!(6, Opcode::Halt.discriminant());
+    assert_eq!(10, Opcode::Push(3).discriminant());
+    assert_eq!(11, Opcode::Jump { target: 1 }.discriminant());
+}
@@ -0,0 +1,42 @@
+use core::convert::TryFrom;
+
+use enum_variant_type::EnumVariantType;
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq))]
+pub enum MyEnum {
+    Struct {
+        #[evt(rename = "id")]
+        field_0: u32,
+        #[evt(rename = "r#type", attr(allow(dead_code)))]
+        field_1: u64,
+    },
+    Tuple(#[evt(strip_attrs)] u32),
+}
+
+#[test]
+fn enum_from_struct_with_renamed_fields() {
+    assert_eq!(
+        MyEnum::Struct {
+            field_0: 1,
+            field_1: 2
+        },
+        MyEnum::from(Struct { id: 1, r#type: 2 })
+    );
+}
+
+#[test]
+fn struct_with_renamed_fields_try_from_enum_ok() {
+    assert_eq!(
+        Ok(Struct { id: 1, r#type: 2 }),
+        Struct::try_from(MyEnum::Struct {
+            field_0: 1,
+            field_1: 2
+        })
+    );
+}
+
+#[test]
+fn struct_with_stripped_attrs_try_from_enum_ok() {
+    assert_eq!(Ok(Tuple(1)), Tuple::try_from(MyEnum::Tuple(1)));
+}
@@ -0,0 +1,83 @@
+use enum_variant_type::EnumVariantType;
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq), fold)]
+pub enum MyEnum {
+    Unit,
+    Tuple(u32, u64),
+    Struct {
+        #[evt(rename = "renamed")]
+        value: String,
+    },
+}
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(fold, module = "generic")]
+pub enum Generic<T> {
+    Value(T),
+    Pair(T, T),
+}
+
+fn describe(my_enum: MyEnum) -> String {
+    my_enum.fold(
+        |Unit| String::from("unit"),
+        |Tuple(a, b)| format!("{}", u64::from(a) + b),
+        |Struct { renamed }| renamed,
+    )
+}
+
+#[test]
+fn fold_passes_struct_to_matching_closure() {
+    assert_eq!("unit", describe(MyEnum::Unit));
+    assert_eq!("3", describe(MyEnum::Tuple(1, 2)));
+    assert_eq!(
+        "a",
+        describe(MyEnum::Struct {
+            value: String::from("a"),
+        })
+    );
+}
+
+#[test]
+fn fold_ref_passes_borrowed_fields() {
+    let my_enum = MyEnum::Struct {
+        value: String::from("abc"),
+    };
+
+    let len = my_enum.fold_ref(
+        |Unit| 0,
+        |TupleRef(a, _)| *a as usize,
+        |StructRef { renamed }| renamed.len(),
+    );
+
+    assert_eq!(3, len);
+}
+
+#[test]
+fn fold_mut_writes_through_to_enum() {
+    let mut my_enum = MyEnum::Tuple(1, 2);
+
+    my_enum.fold_mut(
+        |Unit| {},
+        |TupleMut(a, b)| {
+            *a += 10;
+            *b += 20;
+        },
+        |StructMut { renamed }| renamed.push('!'),
+    );
+
+    assert_eq!(MyEnum::Tuple(11, 22), my_enum);
+}
+
+#[test]
+fn fold_supports_generic_enums() {
+    let mut generic = Generic::Value(1u8);
+
+    generic.fold_mut(
+        |generic::ValueMut(value)| *value += 1,
+        |generic::PairMut(a, _)| *a += 1,
+    );
+    let value = generic.fold(|generic::Value(value)| value, |generic::Pair(a, b)| a + b);
+
+    assert_eq!(2, value);
+}
@@ -0,0 +1,51 @@
+use core::convert::TryFrom;
+
+use enum_variant_type::EnumVariantType;
+
+pub trait Marker {}
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq), implement_marker_traits(Marker))]
+pub enum MyEnum {
+    Unit,
+    #[evt(skip(try_from))]
+    Even(u32),
+    #[evt(impls(from))]
+    Odd(u32),
+}
+
+/// Hand written struct, as `Odd` only generates the `From` impl.
+#[derive(Debug, PartialEq)]
+pub struct Odd(pub u32);
+
+impl TryFrom<MyEnum> for Even {
+    type Error = MyEnum;
+
+    fn try_from(enum_variant: MyEnum) -> Result<Self, Self::Error> {
+        match enum_variant {
+            MyEnum::Even(n) if n % 2 == 0 => Ok(Even(n)),
+            enum_variant => Err(enum_variant),
+        }
+    }
+}
+
+fn is_marker<T: Marker>(_: &T) -> bool {
+    true
+}
+
+#[test]
+fn hand_written_try_from_is_used() {
+    assert_eq!(Ok(Even(2)), Even::try_from(MyEnum::Even(2)));
+    assert_eq!(Err(MyEnum::Even(3)), Even::try_from(MyEnum::Even(3)));
+}
+
+#[test]
+fn generated_from_is_used_with_hand_written_struct() {
+    assert_eq!(MyEnum::Odd(3), MyEnum::from(Odd(3)));
+}
+
+#[test]
+fn marker_traits_are_implemented_for_unskipped_impls() {
+    assert!(is_marker(&Unit));
+    assert!(is_marker(&Even(2)));
+}
@@ -0,0 +1,83 @@
+use enum_variant_type::EnumVariantType;
+
+#[derive(Debug, EnumVariantType)]
+#[evt(kind_set)]
+pub enum MyEnum {
+    Unit,
+    Tuple(u32),
+    Struct {
+        value: String,
+    },
+    #[evt(skip)]
+    Skipped,
+}
+
+const UNIT_OR_TUPLE: MyEnumSet = MyEnumSet::of::<Unit>().union(MyEnumSet::of::<Tuple>());
+
+#[test]
+fn set_is_backed_by_smallest_integer() {
+    assert_eq!(1, core::mem::size_of::<MyEnumSet>());
+    assert_eq!(0b0011, UNIT_OR_TUPLE.bits());
+    assert_eq!(0b1111, MyEnumSet::all().bits());
+}
+
+#[test]
+fn insert_remove_and_contains() {
+    let mut set = MyEnumSet::new();
+    assert!(set.is_empty());
+
+    assert!(set.insert(MyEnumKind::Struct));
+    assert!(!set.insert(MyEnumKind::Struct));
+    assert!(set.insert(MyEnumKind::Skipped));
+    assert_eq!(2, set.len());
+    assert!(set.contains(&MyEnum::Skipped));
+    assert!(set.contains(&MyEnum::Struct {
+        value: String::new(),
+    }));
+    assert!(!set.contains(&MyEnum::Tuple(1)));
+
+    assert!(set.remove(MyEnumKind::Skipped));
+    assert!(!set.remove(MyEnumKind::Skipped));
+    assert!(!set.contains_kind(MyEnumKind::Skipped));
+}
+
+#[test]
+fn set_operations() {
+    let tuple_or_struct = MyEnumSet::of::<Tuple>() | MyEnumSet::of::<Struct>();
+
+    assert_eq!(MyEnumSet::of::<Tuple>(), UNIT_OR_TUPLE & tuple_or_struct);
+    assert_eq!(MyEnumSet::of::<Unit>(), UNIT_OR_TUPLE - tuple_or_struct);
+    assert_eq!(
+        MyEnumSet::of::<Unit>() | MyEnumSet::of::<Struct>(),
+        UNIT_OR_TUPLE ^ tuple_or_struct
+    );
+    assert_eq!(
+        MyEnumSet::of::<Struct>() | MyEnumSet::from(MyEnumKind::Skipped),
+        !UNIT_OR_TUPLE
+    );
+}
+
+#[test]
+fn iter_yields_kinds_in_declaration_order() {
+    let set = [MyEnumKind::Skipped, MyEnumKind::Unit]
+        .into_iter()
+        .collect::<MyEnumSet>();
+
+    assert_eq!(
+        vec![MyEnumKind::Unit, MyEnumKind::Skipped],
+        set.iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn debug_prints_variant_names() {
+    assert_eq!("{Unit, Tuple}", format!("{UNIT_OR_TUPLE:?}"));
+    assert_eq!("{}", format!("{:?}", MyEnumSet::new()));
+}
+
+#[test]
+fn kind_is_returned_for_each_variant() {
+    assert_eq!(MyEnumKind::Tuple, MyEnum::Tuple(1).kind());
+    assert_eq!("Struct", MyEnumKind::Struct.name());
+    assert_eq!(3, MyEnumKind::Skipped.index());
+}
@@ -0,0 +1,49 @@
+use enum_variant_type::EnumVariantType;
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq), map)]
+pub enum MyEnum {
+    Unit,
+    Tuple(u32, u64),
+    Struct {
+        value: String,
+    },
+    #[evt(skip(from))]
+    Count(u8),
+}
+
+#[test]
+fn map_transforms_matching_variant() {
+    let my_enum = MyEnum::Tuple(1, 2).map_tuple(|Tuple(a, b)| Tuple(a + 1, b * 2));
+
+    assert_eq!(MyEnum::Tuple(2, 4), my_enum);
+}
+
+#[test]
+fn map_passes_other_variants_through() {
+    let my_enum = MyEnum::Unit.map_struct(|Struct { value }| Struct { value: value + "!" });
+
+    assert_eq!(MyEnum::Unit, my_enum);
+}
+
+#[test]
+fn and_then_replaces_matching_variant() {
+    let my_enum = MyEnum::Struct {
+        value: String::from("a"),
+    }
+    .and_then_struct(|Struct { value }| {
+        if value.is_empty() {
+            MyEnum::Unit
+        } else {
+            MyEnum::Tuple(value.len() as u32, 0)
+        }
+    });
+    assert_eq!(MyEnum::Tuple(1, 0), my_enum);
+
+    let my_enum = MyEnum::Count(3).and_then_count(|Count(n)| MyEnum::Tuple(n.into(), 0));
+    assert_eq!(MyEnum::Tuple(3, 0), my_enum);
+    assert_eq!(
+        MyEnum::Unit,
+        MyEnum::Unit.and_then_unit(|Unit| MyEnum::Unit)
+    );
+}
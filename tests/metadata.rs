@@ -0,0 +1,68 @@
+use enum_variant_type::{EnumVariantType, VariantInfo};
+
+#[derive(Debug, EnumVariantType)]
+#[evt(metadata)]
+pub enum MyEnum {
+    Unit,
+    Tuple(u32, Vec<u8>),
+    Struct {
+        #[evt(rename = "renamed")]
+        value: u64,
+        #[evt(skip)]
+        cache: Option<u8>,
+    },
+    #[evt(skip)]
+    Skipped {
+        r#type: &'static str,
+        callback: fn(u8) -> (u8, u8),
+        map: std::collections::HashMap<String, [u8; 3]>,
+    },
+}
+
+#[test]
+fn struct_metadata_constants_are_generated() {
+    assert_eq!("Unit", Unit::VARIANT_NAME);
+    assert_eq!(0, Unit::VARIANT_INDEX);
+    assert!(Unit::FIELD_NAMES.is_empty());
+
+    assert_eq!("Tuple", Tuple::VARIANT_NAME);
+    assert_eq!(1, Tuple::VARIANT_INDEX);
+    assert_eq!(&["0", "1"], Tuple::FIELD_NAMES);
+    assert_eq!(&["u32", "Vec < u8 >"], Tuple::FIELD_TYPES);
+}
+
+#[test]
+fn struct_field_metadata_describes_generated_struct() {
+    assert_eq!(2, Struct::VARIANT_INDEX);
+    assert_eq!(&["renamed"], Struct::FIELD_NAMES);
+    assert_eq!(&["u64"], Struct::FIELD_TYPES);
+}
+
+#[test]
+fn enum_variants_include_skipped_variants() {
+    assert_eq!(4, MyEnum::VARIANT_COUNT);
+    assert_eq!(
+        VariantInfo {
+            name: "Struct",
+            index: 2,
+            field_names: &["value", "cache"],
+            field_types: &["u64", "Option < u8 >"],
+            has_struct: true,
+        },
+        MyEnum::VARIANTS[2]
+    );
+    assert_eq!(
+        VariantInfo {
+            name: "Skipped",
+            index: 3,
+            field_names: &["type", "callback", "map"],
+            field_types: &[
+                "& 'static str",
+                "fn(u8) -> (u8, u8)",
+                "std :: collections :: HashMap < String, [u8; 3] >",
+            ],
+            has_struct: false,
+        },
+        MyEnum::VARIANTS[3]
+    );
+}
@@ -0,0 +1,51 @@
+use core::convert::TryFrom;
+
+use enum_variant_type::EnumVariantType;
+
+pub mod payload {
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Payload(pub u32);
+}
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq), module)]
+pub enum Snake {
+    Unit,
+}
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(
+    derive(Debug, PartialEq),
+    module(
+        name = "nested::r#type",
+        vis = "pub(crate)",
+        attrs(doc = "Variant types of `MyEnum`."),
+        imports(crate::payload::Payload),
+        reexport,
+    )
+)]
+pub enum MyEnum {
+    Unit,
+    Tuple(Payload),
+}
+
+use payload::Payload;
+
+#[test]
+fn module_name_defaults_to_snake_case_enum_name() {
+    assert_eq!(Snake::Unit, Snake::from(snake::Unit));
+}
+
+#[test]
+fn structs_are_placed_in_nested_module() {
+    assert_eq!(MyEnum::Unit, MyEnum::from(nested::r#type::Unit));
+    assert_eq!(
+        Ok(nested::r#type::Tuple(Payload(1))),
+        nested::r#type::Tuple::try_from(MyEnum::Tuple(Payload(1)))
+    );
+}
+
+#[test]
+fn structs_are_reexported_alongside_enum() {
+    assert_eq!(MyEnum::Tuple(Payload(2)), MyEnum::from(Tuple(Payload(2))));
+}
@@ -0,0 +1,54 @@
+use core::convert::TryFrom;
+
+use enum_variant_type::EnumVariantType;
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq), opt_in)]
+pub enum MyEnum {
+    Unit,
+    #[evt(include)]
+    Tuple(u32, u64),
+    #[evt(derive(Clone))]
+    Struct {
+        field_0: u32,
+        field_1: u64,
+    },
+    #[evt(skip)]
+    Skipped,
+}
+
+/// Not generated, as `MyEnum::Unit` is not tagged.
+#[derive(Debug, PartialEq)]
+pub struct Unit;
+
+/// Not generated, as `MyEnum::Skipped` is skipped.
+#[derive(Debug, PartialEq)]
+pub struct Skipped;
+
+#[test]
+fn included_variant_struct_is_generated() {
+    assert_eq!(Ok(Tuple(1, 2)), Tuple::try_from(MyEnum::Tuple(1, 2)));
+}
+
+#[test]
+fn tagged_variant_struct_is_generated() {
+    let named = Struct {
+        field_0: 1,
+        field_1: 2,
+    };
+
+    assert_eq!(
+        MyEnum::Struct {
+            field_0: 1,
+            field_1: 2
+        },
+        MyEnum::from(named.clone())
+    );
+}
+
+#[test]
+fn untagged_and_skipped_variant_structs_are_not_generated() {
+    // These would conflict with the hand written structs if they were generated.
+    assert_eq!("Unit", format!("{:?}", Unit));
+    assert_eq!("Skipped", format!("{:?}", Skipped));
+}
@@ -0,0 +1,31 @@
+use core::mem;
+
+use enum_variant_type::EnumVariantType;
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[repr(C, u8, align(16))]
+pub enum Aligned {
+    Unit,
+    Tuple(u8, u8),
+    #[evt(repr(transparent))]
+    Wrapper(u64),
+    #[evt(repr(C, packed))]
+    Packed {
+        field_0: u8,
+        field_1: u32,
+    },
+}
+
+#[test]
+fn align_is_propagated_to_structs() {
+    assert_eq!(16, mem::align_of::<Unit>());
+    assert_eq!(16, mem::align_of::<Tuple>());
+}
+
+#[test]
+fn variant_repr_overrides_enum_repr() {
+    assert_eq!(mem::align_of::<u64>(), mem::align_of::<Wrapper>());
+    assert_eq!(mem::size_of::<u64>(), mem::size_of::<Wrapper>());
+    assert_eq!(1, mem::align_of::<Packed>());
+    assert_eq!(5, mem::size_of::<Packed>());
+}
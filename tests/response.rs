@@ -0,0 +1,72 @@
+use core::convert::TryFrom;
+
+use enum_variant_type::{EnumVariantType, Request};
+
+#[derive(Debug, PartialEq)]
+pub struct User {
+    name: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Deleted(bool);
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq), response_derive(Debug, PartialEq))]
+pub enum Rpc {
+    #[evt(response = User)]
+    GetUser {
+        id: u32,
+    },
+    #[evt(response = Deleted)]
+    DeleteUser(u32),
+    Ping,
+}
+
+fn transport(rpc: Rpc) -> RpcResponse {
+    match rpc {
+        Rpc::GetUser { id } => RpcResponse::GetUser(User {
+            name: format!("user_{id}"),
+        }),
+        Rpc::DeleteUser(id) => RpcResponse::DeleteUser(Deleted(id == 1)),
+        Rpc::Ping => unreachable!("`Ping` has no response."),
+    }
+}
+
+#[test]
+fn call_converts_request_and_response() {
+    let user = Rpc::call(GetUser { id: 3 }, transport);
+    assert_eq!(
+        Ok(User {
+            name: String::from("user_3")
+        }),
+        user
+    );
+
+    let deleted: Result<<DeleteUser as Request>::Response, RpcResponse> =
+        Rpc::call(DeleteUser(1), transport);
+    assert_eq!(Ok(Deleted(true)), deleted);
+}
+
+#[test]
+fn call_returns_mismatched_response() {
+    let response = Rpc::call(GetUser { id: 3 }, |_| {
+        RpcResponse::DeleteUser(Deleted(false))
+    });
+
+    assert_eq!(Err(RpcResponse::DeleteUser(Deleted(false))), response);
+}
+
+#[test]
+fn response_enum_converts_to_and_from_responses() {
+    let response = RpcResponse::from(Deleted(true));
+
+    assert_eq!(RpcResponse::DeleteUser(Deleted(true)), response);
+    assert_eq!(
+        Err(RpcResponse::DeleteUser(Deleted(true))),
+        User::try_from(response)
+    );
+    assert_eq!(
+        Ok(Deleted(false)),
+        Deleted::try_from(RpcResponse::from(Deleted(false)))
+    );
+}
@@ -0,0 +1,109 @@
+use enum_variant_type::EnumVariantType;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, Deserialize, EnumVariantType, PartialEq, Serialize)]
+#[evt(derive(Debug, PartialEq), serde_repr = "external")]
+pub enum External {
+    Unit,
+    Tuple(u32, u64),
+    #[serde(rename = "named")]
+    Struct {
+        #[serde(rename = "first")]
+        field_0: u32,
+        #[evt(rename = "second")]
+        field_1: u64,
+    },
+}
+
+#[derive(Debug, Deserialize, EnumVariantType, PartialEq, Serialize)]
+#[evt(derive(Debug, PartialEq), serde_repr = "internal(type)")]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Internal {
+    Ping,
+    Move { x: i32, y: i32 },
+}
+
+#[derive(Debug, Deserialize, EnumVariantType, PartialEq, Serialize)]
+#[evt(derive(Debug, PartialEq), serde_repr = "adjacent(t, c)")]
+#[serde(tag = "t", content = "c")]
+pub enum Adjacent<T> {
+    Value(T),
+    Pair(T, T),
+}
+
+#[test]
+fn external_structs_serialize_like_their_variant() {
+    assert_eq!(json!("Unit"), serde_json::to_value(Unit).unwrap());
+    assert_eq!(
+        serde_json::to_value(External::Tuple(1, 2)).unwrap(),
+        serde_json::to_value(Tuple(1, 2)).unwrap()
+    );
+    assert_eq!(
+        json!({ "named": { "first": 1, "field_1": 2 } }),
+        serde_json::to_value(Struct {
+            field_0: 1,
+            second: 2
+        })
+        .unwrap()
+    );
+}
+
+#[test]
+fn external_structs_deserialize_from_their_variant() {
+    let json = serde_json::to_string(&External::Struct {
+        field_0: 1,
+        field_1: 2,
+    })
+    .unwrap();
+    let named = serde_json::from_str::<Struct>(&json).unwrap();
+
+    assert_eq!(
+        Struct {
+            field_0: 1,
+            second: 2
+        },
+        named
+    );
+    assert_eq!(json, serde_json::to_string(&named).unwrap());
+}
+
+#[test]
+fn deserializing_a_different_variant_is_an_error() {
+    let error = serde_json::from_str::<Tuple>("\"Unit\"").unwrap_err();
+
+    assert_eq!("expected the `External::Tuple` variant", error.to_string());
+}
+
+#[test]
+fn internal_structs_include_the_tag() {
+    assert_eq!(
+        json!({ "type": "ping" }),
+        serde_json::to_value(Ping).unwrap()
+    );
+    assert_eq!(
+        json!({ "type": "move", "x": 1, "y": 2 }),
+        serde_json::to_value(Move { x: 1, y: 2 }).unwrap()
+    );
+    assert_eq!(
+        Move { x: 1, y: 2 },
+        serde_json::from_value::<Move>(json!({ "type": "move", "x": 1, "y": 2 })).unwrap()
+    );
+    assert!(serde_json::from_value::<Move>(json!({ "type": "ping" })).is_err());
+}
+
+#[test]
+fn adjacent_structs_include_the_tag_and_content() {
+    assert_eq!(
+        json!({ "t": "Pair", "c": [1, 2] }),
+        serde_json::to_value(Pair(1, 2)).unwrap()
+    );
+    assert_eq!(
+        serde_json::to_value(Adjacent::Value(String::from("a"))).unwrap(),
+        serde_json::to_value(Value(String::from("a"))).unwrap()
+    );
+    assert_eq!(
+        Value(3u8),
+        serde_json::from_value::<Value<u8>>(json!({ "t": "Value", "c": 3 })).unwrap()
+    );
+}
@@ -0,0 +1,42 @@
+use core::convert::TryFrom;
+
+use enum_variant_type::EnumVariantType;
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq))]
+pub enum MyEnum {
+    Struct {
+        field_0: u32,
+        #[evt(skip, default = 10 + 2)]
+        field_1: u64,
+    },
+    Tuple(u32, #[evt(skip)] u64),
+}
+
+#[test]
+fn enum_from_struct_uses_default_expression_for_skipped_field() {
+    assert_eq!(
+        MyEnum::Struct {
+            field_0: 1,
+            field_1: 12
+        },
+        MyEnum::from(Struct { field_0: 1 })
+    );
+}
+
+#[test]
+fn enum_from_tuple_uses_default_value_for_skipped_field() {
+    assert_eq!(MyEnum::Tuple(1, 0), MyEnum::from(Tuple(1)));
+}
+
+#[test]
+fn struct_try_from_enum_drops_skipped_field() {
+    assert_eq!(
+        Ok(Struct { field_0: 1 }),
+        Struct::try_from(MyEnum::Struct {
+            field_0: 1,
+            field_1: 2
+        })
+    );
+    assert_eq!(Ok(Tuple(1)), Tuple::try_from(MyEnum::Tuple(1, 2)));
+}
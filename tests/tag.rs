@@ -0,0 +1,32 @@
+use enum_variant_type::EnumVariantType;
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+pub enum Message {
+    #[evt(tag = 0x12)]
+    Ping,
+    #[evt(tag = 0x01)]
+    Data(Vec<u8>),
+    #[evt(tag = 7, skip)]
+    Close { reason: u8 },
+}
+
+#[test]
+fn structs_have_tag_constants() {
+    assert_eq!(0x12, Ping::TAG);
+    assert_eq!(0x01, Data::TAG);
+}
+
+#[test]
+fn tag_returns_variant_tag() {
+    assert_eq!(0x12, Message::Ping.tag());
+    assert_eq!(0x01, Message::Data(vec![1]).tag());
+    assert_eq!(7, Message::Close { reason: 0 }.tag());
+}
+
+#[test]
+fn kind_from_tag_returns_kind_of_tagged_variant() {
+    assert_eq!(Some(MessageKind::Ping), Message::kind_from_tag(0x12));
+    assert_eq!(Some(MessageKind::Data), Message::kind_from_tag(0x01));
+    assert_eq!(Some(MessageKind::Close), Message::kind_from_tag(7));
+    assert_eq!(None, Message::kind_from_tag(0));
+}
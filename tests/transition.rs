@@ -0,0 +1,109 @@
+use enum_variant_type::{EnumVariantType, IllegalTransition};
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq))]
+pub enum Conn {
+    #[evt(transition(to = Connecting, via = "connect"))]
+    Idle,
+    #[evt(
+        transition(to = Open, via = "open"),
+        transition(to = Idle, via = "abort")
+    )]
+    Connecting {
+        addr: String,
+    },
+    #[evt(transition(to = Closed, via = "close"))]
+    Open {
+        addr: String,
+        stream: u32,
+    },
+    Closed(u8),
+}
+
+#[test]
+fn transition_methods_move_shared_fields_and_take_the_rest() {
+    let connecting = Idle.connect(String::from("localhost"));
+    assert_eq!(
+        Connecting {
+            addr: String::from("localhost")
+        },
+        connecting
+    );
+
+    let open = connecting.open(7);
+    assert_eq!(
+        Open {
+            addr: String::from("localhost"),
+            stream: 7,
+        },
+        open
+    );
+
+    assert_eq!(Closed(1), open.close(1));
+}
+
+#[test]
+fn transition_methods_can_return_to_unit_structs() {
+    let connecting = Connecting {
+        addr: String::from("localhost"),
+    };
+
+    assert_eq!(Idle, connecting.abort());
+}
+
+#[test]
+fn transition_returns_next_when_declared() {
+    let conn = Conn::Connecting {
+        addr: String::from("localhost"),
+    };
+    let open = Open {
+        addr: String::from("localhost"),
+        stream: 7,
+    };
+
+    assert!(conn.can_transition(&Conn::Idle));
+    assert_eq!(
+        Ok(Conn::Open {
+            addr: String::from("localhost"),
+            stream: 7,
+        }),
+        conn.transition(open)
+    );
+}
+
+#[test]
+fn transition_returns_error_when_not_declared() {
+    assert!(!Conn::Idle.can_transition(&Conn::Closed(0)));
+    assert_eq!(
+        Err(IllegalTransition {
+            from: Conn::Idle,
+            to: Conn::Closed(0),
+        }),
+        Conn::Idle.transition(Closed(0))
+    );
+    assert_eq!(
+        Err(IllegalTransition {
+            from: Conn::Closed(0),
+            to: Conn::Idle,
+        }),
+        Conn::Closed(0).transition(Conn::Idle)
+    );
+}
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq), module = "door")]
+pub enum Door {
+    #[evt(transition(to = Opened, via = "open"))]
+    Shut,
+    #[evt(module = "opened", transition(to = Shut, via = "shut"))]
+    Opened { angle: u8 },
+}
+
+#[test]
+fn transition_methods_support_structs_in_modules() {
+    use door::{opened::Opened, Shut};
+
+    let opened = Shut.open(90);
+    assert_eq!(Opened { angle: 90 }, opened);
+    assert_eq!(Shut, opened.shut());
+}
@@ -0,0 +1,69 @@
+use enum_variant_type::EnumVariantType;
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq), tuple_conv)]
+pub enum MyEnum {
+    Unit,
+    Tuple(u32, u64),
+    Struct {
+        field_0: u32,
+        #[evt(rename = "second")]
+        field_1: String,
+    },
+    Single(u8),
+    #[evt(skip(from))]
+    NoFrom(u32, u64),
+}
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq), module = "generic", tuple_conv)]
+pub enum Generic<T> {
+    Pair(T, T),
+}
+
+#[test]
+fn structs_convert_from_tuples() {
+    assert_eq!(Tuple(1, 2), Tuple::from((1, 2)));
+    assert_eq!(
+        Struct {
+            field_0: 1,
+            second: String::from("a")
+        },
+        Struct::from((1, String::from("a")))
+    );
+    assert_eq!(Single(3), Single::from((3,)));
+}
+
+#[test]
+fn tuples_convert_from_structs_in_field_order() {
+    assert_eq!((1, 2), <(u32, u64)>::from(Tuple(1, 2)));
+    assert_eq!(
+        (1, String::from("a")),
+        <(u32, String)>::from(Struct {
+            field_0: 1,
+            second: String::from("a")
+        })
+    );
+}
+
+#[test]
+fn enum_converts_from_tuples() {
+    assert_eq!(MyEnum::Tuple(1, 2), MyEnum::from((1u32, 2u64)));
+    assert_eq!(
+        MyEnum::Struct {
+            field_0: 1,
+            field_1: String::from("a")
+        },
+        MyEnum::from((1, String::from("a")))
+    );
+    assert_eq!(
+        vec![MyEnum::Single(1), MyEnum::Single(2)],
+        [(1,), (2,)]
+            .into_iter()
+            .map(MyEnum::from)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(NoFrom(1, 2), NoFrom::from((1, 2)));
+    assert_eq!(Generic::Pair(1, 2), Generic::from((1, 2)));
+    assert_eq!((1, 2), <(i32, i32)>::from(generic::Pair(1, 2)));
+}
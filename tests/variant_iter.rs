@@ -0,0 +1,92 @@
+use enum_variant_type::{EnumVariantType, VariantIterExt};
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq), views)]
+pub enum MyEnum {
+    Unit,
+    Tuple(u32),
+    Struct { value: String },
+}
+
+fn my_enums() -> Vec<MyEnum> {
+    vec![
+        MyEnum::Tuple(1),
+        MyEnum::Unit,
+        MyEnum::Struct {
+            value: String::from("a"),
+        },
+        MyEnum::Tuple(2),
+    ]
+}
+
+#[test]
+fn variants_yields_matching_structs() {
+    let tuples = my_enums()
+        .into_iter()
+        .variants::<Tuple>()
+        .collect::<Vec<_>>();
+
+    assert_eq!(vec![Tuple(1), Tuple(2)], tuples);
+}
+
+#[test]
+fn variant_refs_yields_borrowed_views() {
+    let my_enums = my_enums();
+
+    let values = my_enums
+        .iter()
+        .variant_refs::<Struct>()
+        .map(|StructRef { value }| value.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["a"], values);
+    assert_eq!(1, my_enums.iter().variant_refs::<Unit>().count());
+}
+
+#[test]
+fn variant_refs_over_mutable_borrows_writes_through() {
+    let mut my_enums = my_enums();
+
+    my_enums
+        .iter_mut()
+        .variant_refs::<Tuple>()
+        .for_each(|TupleMut(value)| *value *= 10);
+
+    assert_eq!(MyEnum::Tuple(10), my_enums[0]);
+    assert_eq!(MyEnum::Tuple(20), my_enums[3]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn partition_variant_splits_matching_and_rest() {
+    let (tuples, rest) = my_enums().into_iter().partition_variant::<Tuple>();
+
+    assert_eq!(vec![Tuple(1), Tuple(2)], tuples);
+    assert_eq!(
+        vec![
+            MyEnum::Unit,
+            MyEnum::Struct {
+                value: String::from("a"),
+            },
+        ],
+        rest
+    );
+}
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq), views)]
+pub enum Generic<T> {
+    Value(T),
+    Pair { left: T, right: u8 },
+}
+
+#[test]
+fn variant_refs_supports_generic_enums() {
+    let generics = [Generic::Value(1u32), Generic::Pair { left: 2, right: 3 }];
+
+    let sums = generics
+        .iter()
+        .variant_refs::<Pair<u32>>()
+        .map(|PairRef { left, right }| *left + u32::from(*right))
+        .collect::<Vec<_>>();
+    assert_eq!(vec![5], sums);
+}
@@ -0,0 +1,68 @@
+use enum_variant_type::EnumVariantType;
+
+#[derive(Debug, EnumVariantType)]
+#[evt(variant_map)]
+pub enum MyEnum {
+    Unit,
+    Tuple(u32),
+    Struct { value: String },
+}
+
+#[test]
+fn get_returns_value_for_variant_struct() {
+    let map = MyEnumVariantMap::from_array([1, 2, 3]);
+
+    assert_eq!(&1, map.get::<Unit>());
+    assert_eq!(&2, map.get::<Tuple>());
+    assert_eq!(&3, map.get::<Struct>());
+    assert_eq!(&2, map.get_for(&MyEnum::Tuple(7)));
+    assert_eq!(3, map[MyEnumKind::Struct]);
+}
+
+#[test]
+fn get_mut_updates_value() {
+    let mut counts = MyEnumVariantMap::<u32>::default();
+    let my_enums = [MyEnum::Unit, MyEnum::Tuple(1), MyEnum::Tuple(2)];
+
+    my_enums
+        .iter()
+        .for_each(|my_enum| *counts.get_for_mut(my_enum) += 1);
+    *counts.get_mut::<Struct>() += 5;
+    counts[MyEnumKind::Unit] += 10;
+
+    assert_eq!([11, 2, 5], counts.into_array());
+}
+
+#[test]
+fn iter_yields_kinds_and_values_in_declaration_order() {
+    let map = MyEnumVariantMap::from_fn(MyEnumKind::name);
+
+    assert_eq!(
+        vec![
+            (MyEnumKind::Unit, &"Unit"),
+            (MyEnumKind::Tuple, &"Tuple"),
+            (MyEnumKind::Struct, &"Struct"),
+        ],
+        map.iter().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        r#"{Unit: "Unit", Tuple: "Tuple", Struct: "Struct"}"#,
+        format!("{map:?}")
+    );
+}
+
+#[derive(Debug, EnumVariantType)]
+#[evt(variant_map)]
+pub enum GenV<V> {
+    A(V),
+    B(V, u8),
+}
+
+#[test]
+fn get_for_supports_enum_generic_named_v() {
+    let mut map = GenVVariantMap::from_array(["a", "b"]);
+
+    assert_eq!(&"b", map.get_for(&GenV::B(1u32, 2)));
+    *map.get_for_mut(&GenV::A(1u32)) = "c";
+    assert_eq!(&"c", map.get::<A<u32>>());
+}
@@ -0,0 +1,57 @@
+use core::convert::TryFrom;
+
+use enum_variant_type::EnumVariantType;
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq), module(name = "cmd", reexport))]
+pub enum Command {
+    #[evt(module = "fs")]
+    Read {
+        path: &'static str,
+    },
+    #[evt(module = "fs")]
+    Write {
+        path: &'static str,
+        len: usize,
+    },
+    #[evt(module = "net::tcp")]
+    Connect(u16),
+    Help,
+}
+
+#[derive(Debug, EnumVariantType, PartialEq)]
+#[evt(derive(Debug, PartialEq))]
+pub enum Message {
+    #[evt(module = "ping")]
+    Ping,
+    Pong,
+}
+
+#[test]
+fn structs_are_grouped_into_variant_modules() {
+    assert_eq!(
+        Command::Read { path: "a" },
+        Command::from(cmd::fs::Read { path: "a" })
+    );
+    assert_eq!(
+        Ok(cmd::fs::Write { path: "b", len: 1 }),
+        cmd::fs::Write::try_from(Command::Write { path: "b", len: 1 })
+    );
+    assert_eq!(
+        Ok(cmd::net::tcp::Connect(80)),
+        cmd::net::tcp::Connect::try_from(Command::Connect(80))
+    );
+    assert_eq!(Command::Help, Command::from(cmd::Help));
+}
+
+#[test]
+fn structs_in_variant_modules_are_reexported() {
+    assert_eq!(Command::Connect(443), Command::from(Connect(443)));
+    assert_eq!(Err(Command::Help), Read::try_from(Command::Help));
+}
+
+#[test]
+fn variant_modules_without_enum_module_are_relative_to_enum() {
+    assert_eq!(Message::Ping, Message::from(ping::Ping));
+    assert_eq!(Message::Pong, Message::from(Pong));
+}